@@ -1,9 +1,9 @@
-use color_eyre::Result;
+use miette::Result;
 use {{crate_name}}::part2::process;
 
 fn main() -> Result<()> {
-    let file = include_str!("../../input.txt");
-    let result = process(file)?;
+    let file = aoc_core::input!("input.txt")?;
+    let result = process(&file)?;
     println!("{}", result);
     Ok(())
 }
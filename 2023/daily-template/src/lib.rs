@@ -1,2 +1,34 @@
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => crate::part1::process,
+    part2 => crate::part2::process,
+}
+
+use aoc_core::Solution;
+use miette::Result;
+
+/// The parsed puzzle input both parts are solved from.
+#[derive(Debug)]
+pub struct Model;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Model = Model;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Model> {
+        todo!("day xx - parse");
+    }
+
+    fn part1(model: &Model) -> Result<usize> {
+        part1::solve(model)
+    }
+
+    fn part2(model: &Model) -> Result<usize> {
+        part2::solve(model)
+    }
+}
@@ -1,19 +1,12 @@
-use color_eyre::Result;
+use crate::{Model, Puzzle};
+use aoc_core::Solution;
+use miette::Result;
 
-pub fn process(input: &str) -> Result<usize> {
+pub(crate) fn solve(model: &Model) -> Result<usize> {
     todo!("day xx - part 1");
     Ok(0)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "";
-        todo!("haven't built test yet");
-        assert_eq!(0, process(input)?);
-        Ok(())
-    }
+pub fn process(input: &str) -> Result<usize> {
+    Puzzle::part1(&Puzzle::parse(input)?)
 }
@@ -2,8 +2,8 @@ use color_eyre::Result;
 use day_01::part2::process;
 
 fn main() -> Result<()> {
-    let file = include_str!("../../input2.txt");
-    let result = process(file)?;
+    let file = aoc_core::input!("input2.txt")?;
+    let result = process(&file)?;
     println!("{}", result);
     Ok(())
 }
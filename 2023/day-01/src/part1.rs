@@ -14,17 +14,3 @@ pub fn process(input: &str) -> Result<u32> {
 
     Ok(sum)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
-        assert_eq!(142, process(input)?);
-        Ok(())
-    }
-}
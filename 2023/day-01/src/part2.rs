@@ -40,20 +40,3 @@ fn process_line(line: &str) -> u32 {
         None => first * 10 + first,
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
-        assert_eq!(281, process(input)?);
-        Ok(())
-    }
-}
@@ -2,8 +2,8 @@ use color_eyre::Result;
 use day_03::part1::process;
 
 fn main() -> Result<()> {
-    let file = include_str!("../../input1.txt");
-    let result = process(file)?;
+    let file = aoc_core::input!("input1.txt")?;
+    let result = process(&file)?;
     println!("{}", result);
     Ok(())
 }
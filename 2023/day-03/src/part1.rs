@@ -89,24 +89,3 @@ pub fn process(input: &str) -> Result<u32> {
 
     Ok(sum)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
-        assert_eq!(4361, process(input)?);
-        Ok(())
-    }
-}
@@ -0,0 +1,143 @@
+use aoc_core::{
+    generate::{Generate, Generated, Rng, Seeded, SliceRandom},
+    Part,
+};
+use std::ops::Range;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Almanacs whose answers are worked out on whole ranges, splitting them
+/// where a map's ranges start and end instead of following every seed.
+pub struct Almanac;
+
+impl Generate for Almanac {
+    /// An almanac of about `size` seeds in ten ranges, mapped through the
+    /// seven maps of the puzzle over numbers up to four times `size`.
+    fn generate(rng: &mut Seeded, size: usize) -> Generated {
+        let size = size.max(10) as u64;
+        let span = size * 4;
+        let seeds = (0..10)
+            .map(|_| {
+                let len = rng.gen_range(1..=size / 5);
+                let start = rng.gen_range(0..span);
+                start..start + len
+            })
+            .collect::<Vec<_>>();
+        let maps = (1..CATEGORIES.len())
+            .map(|_| seed_map(rng, span))
+            .collect::<Vec<_>>();
+
+        let part1 = seeds
+            .iter()
+            .flat_map(|range| [range.start, range.end - range.start])
+            .map(|seed| maps.iter().fold(seed, |seed, map| translate(map, seed)))
+            .min();
+        let part2 = maps
+            .iter()
+            .fold(seeds.clone(), |ranges, map| convert(map, ranges))
+            .iter()
+            .map(|range| range.start)
+            .min();
+
+        let mut input = String::from("seeds:");
+        for range in &seeds {
+            input += &format!(" {} {}", range.start, range.end - range.start);
+        }
+        for (categories, map) in CATEGORIES.windows(2).zip(&maps) {
+            input += &format!("\n\n{}-to-{} map:", categories[0], categories[1]);
+            for (destination, source, len) in map {
+                input += &format!("\n{destination} {source} {len}");
+            }
+        }
+        input.push('\n');
+
+        let mut generated = Generated::new(input);
+        if let Some(part1) = part1 {
+            generated = generated.with_answer(Part::One, part1);
+        }
+        if let Some(part2) = part2 {
+            generated = generated.with_answer(Part::Two, part2);
+        }
+        generated
+    }
+}
+
+/// Lines of a map, with disjoint source ranges below `span` listed in any
+/// order.
+fn seed_map(rng: &mut Seeded, span: u64) -> Vec<(u64, u64, u64)> {
+    let mut lines = Vec::new();
+    let mut source = rng.gen_range(0..span / 8);
+    while source < span {
+        let len = rng.gen_range(1..=span / 4).min(span - source);
+        lines.push((rng.gen_range(0..span), source, len));
+        source += len + rng.gen_range(0..span / 8);
+    }
+    lines.shuffle(rng);
+    lines
+}
+
+fn translate(map: &[(u64, u64, u64)], seed: u64) -> u64 {
+    map.iter()
+        .find(|(_, source, len)| (*source..source + len).contains(&seed))
+        .map_or(seed, |(destination, source, _)| seed - source + destination)
+}
+
+/// Maps every range through `map`, splitting ranges that straddle the edge
+/// of one of its source ranges.
+fn convert(map: &[(u64, u64, u64)], ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    let mut mapped = Vec::new();
+    let mut pending = ranges;
+    for &(destination, source, len) in map {
+        let mut rest = Vec::new();
+        for range in pending {
+            let (start, end) = (range.start.max(source), range.end.min(source + len));
+            if start >= end {
+                rest.push(range);
+                continue;
+            }
+            mapped.push(start - source + destination..end - source + destination);
+            if range.start < start {
+                rest.push(range.start..start);
+            }
+            if end < range.end {
+                rest.push(end..range.end);
+            }
+        }
+        pending = rest;
+    }
+    mapped.extend(pending);
+    mapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+    use aoc_core::Answer;
+    use color_eyre::Result;
+
+    #[test]
+    fn test_generated() -> Result<()> {
+        for (seed, size) in [(1, 30), (2, 500), (3, 5000)] {
+            let case = Almanac::seeded(seed, size);
+            assert_eq!(
+                case.answer(Part::One),
+                Some(&Answer::from(part1::process(&case.input)?))
+            );
+            assert_eq!(
+                case.answer(Part::Two),
+                Some(&Answer::from(part2::process(&case.input)?))
+            );
+        }
+        Ok(())
+    }
+}
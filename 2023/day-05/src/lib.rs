@@ -1,2 +1,17 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+pub use generate::Almanac;
+
+/// The brute-force first attempt, kept as the oracle `part2` is checked
+/// against.
+#[cfg(test)]
+#[path = "part2-slow.rs"]
+mod part2_slow;
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => crate::part1::process,
+    part2 => crate::part2::process,
+}
@@ -91,7 +91,7 @@ pub fn process(input: &str) -> Result<usize> {
     for map in &maps {
         for (&dest_range, &source_range, &range_len) in map.iter() {
             for num in step.clone() {
-                if (source_range..=source_range + range_len).contains(&num) {
+                if (source_range..source_range + range_len).contains(&num) {
                     step.retain(|&x| x != num);
                     let dest = num + dest_range - source_range;
                     next_step.push(dest);
@@ -105,47 +105,3 @@ pub fn process(input: &str) -> Result<usize> {
 
     Ok(*step.iter().min().unwrap_or(&0))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4";
-        assert_eq!(35, process(input)?);
-        Ok(())
-    }
-}
@@ -22,7 +22,7 @@ impl FromStr for Seeds {
             .flat_map(|chunk| {
                 let start = chunk[0];
                 let end = start + chunk[1];
-                (start..=end).collect::<Vec<usize>>()
+                (start..end).collect::<Vec<usize>>()
             })
             .collect();
 
@@ -100,7 +100,7 @@ pub fn process(input: &str) -> Result<usize> {
     for map in &maps {
         for (&dest_range, &source_range, &range_len) in map.iter() {
             for num in step.clone() {
-                if (source_range..=source_range + range_len).contains(&num) {
+                if (source_range..source_range + range_len).contains(&num) {
                     step.retain(|&x| x != num);
                     let dest = num + dest_range - source_range;
                     next_step.push(dest);
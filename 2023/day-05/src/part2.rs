@@ -78,43 +78,61 @@ pub fn process(input: &str) -> Result<u64> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    /// Maps with disjoint source ranges, listed in any order.
+    fn seed_map() -> impl Strategy<Value = Vec<(u64, u64, u64)>> {
+        prop::collection::vec((0..20u64, 1..30u64, 0..300u64), 1..5)
+            .prop_map(|entries| {
+                let mut source = 0;
+                entries
+                    .into_iter()
+                    .map(|(gap, len, destination)| {
+                        source += gap;
+                        let line = (destination, source, len);
+                        source += len;
+                        line
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .prop_shuffle()
+    }
 
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
+    fn almanac() -> impl Strategy<Value = String> {
+        (
+            prop::collection::vec((0..300u64, 1..30u64), 1..4),
+            prop::collection::vec(seed_map(), 1..8),
+        )
+            .prop_map(|(seeds, maps)| {
+                let seeds = seeds
+                    .iter()
+                    .map(|(start, len)| format!("{start} {len}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                let maps = maps
+                    .iter()
+                    .enumerate()
+                    .map(|(i, lines)| {
+                        let lines = lines
+                            .iter()
+                            .map(|(destination, source, len)| {
+                                format!("{destination} {source} {len}")
+                            })
+                            .collect::<Vec<_>>()
+                            .join("\n");
+                        format!("step-{i}-to-step-{} map:\n{lines}", i + 1)
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n");
+                format!("seeds: {seeds}\n\n{maps}\n")
+            })
+    }
 
-humidity-to-location map:
-60 56 37
-56 93 4";
-        assert_eq!(46, process(input)?);
-        Ok(())
+    proptest! {
+        #[test]
+        fn test_matches_slow(input in almanac()) {
+            let expected = crate::part2_slow::process(&input).unwrap();
+            prop_assert_eq!(process(&input).unwrap(), expected as u64);
+        }
     }
 }
@@ -74,16 +74,3 @@ pub fn process(input: &str) -> Result<usize> {
         .product();
     Ok(total)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "Time:      7  15   30
-Distance:  9  40  200";
-        assert_eq!(288, process(input)?);
-        Ok(())
-    }
-}
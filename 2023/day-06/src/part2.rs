@@ -64,17 +64,3 @@ pub fn process(input: &str) -> Result<usize> {
 
     Ok(total.count())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "Time:      7  15   30
-Distance:  9  40  200
-";
-        assert_eq!(71503, process(input)?);
-        Ok(())
-    }
-}
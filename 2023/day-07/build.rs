@@ -0,0 +1,4 @@
+fn main() -> std::io::Result<()> {
+    // the example tests are generated from `examples/`
+    aoc_core::examples::write_cases()
+}
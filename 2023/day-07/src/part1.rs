@@ -170,19 +170,3 @@ pub fn process(input: &str) -> color_eyre::Result<usize> {
 
     Ok(total)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> color_eyre::Result<()> {
-        let input = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-        assert_eq!(6440, process(input)?);
-        Ok(())
-    }
-}
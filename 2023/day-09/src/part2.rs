@@ -32,17 +32,3 @@ pub fn process(input: &str) -> Result<i64> {
     let total = input.lines().map(process_line).sum();
     Ok(total)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
-        assert_eq!(2, process(input)?);
-        Ok(())
-    }
-}
@@ -116,22 +116,6 @@ mod tests {
 
     use super::*;
 
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-        assert_eq!(374, process(input)?);
-        Ok(())
-    }
-
     #[test]
     fn test_expand_no_expansion_needed() -> Result<()> {
         let input = vec![
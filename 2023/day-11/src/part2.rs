@@ -4,16 +4,3 @@ pub fn process(input: &str) -> Result<usize> {
     todo!("day xx - part 2");
     Ok(0)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "";
-        todo!("haven't built test yet");
-        assert_eq!(0, process(input)?);
-        Ok(())
-    }
-}
@@ -111,18 +111,6 @@ pub fn process(input: &str) -> Result<usize> {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "???.### 1,1,3
-    .??..??...?##. 1,1,3
-    ?#?#?#?#?#?#?#? 1,3,1,6
-    ????.#...#... 4,1,1
-    ????.######..#####. 1,6,5
-    ?###???????? 3,2,1";
-        assert_eq!(21, process(input)?);
-        Ok(())
-    }
-
     #[test]
     fn test_from_str() -> Result<()> {
         let input = "???.### 1,1,3";
@@ -85,20 +85,3 @@ pub fn process(input: &str) -> Result<usize> {
         .map(|(_, puzzle)| puzzle.possible_solution_count());
     Ok(sum.sum())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "???.### 1,1,3
-    .??..??...?##. 1,1,3
-    ?#?#?#?#?#?#?#? 1,3,1,6
-    ????.#...#... 4,1,1
-    ????.######..#####. 1,6,5
-    ?###???????? 3,2,1";
-        assert_eq!(525152, process(input)?);
-        Ok(())
-    }
-}
@@ -83,27 +83,6 @@ mod tests {
 
     use super::*;
 
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
-        assert_eq!(405, process(input)?);
-        Ok(())
-    }
-
     #[test]
     fn test_vertical() -> Result<()> {
         let input = "#.##..##.
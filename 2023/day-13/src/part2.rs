@@ -105,27 +105,6 @@ mod tests {
 
     use super::*;
 
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
-        assert_eq!(400, process(input)?);
-        Ok(())
-    }
-
     #[test]
     fn test_horizontal() -> Result<()> {
         let input = "#...##..#
@@ -94,24 +94,3 @@ pub fn process(input: &str) -> Result<usize> {
         .sum::<i32>();
     Ok(sum as usize)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        assert_eq!(136, process(input)?);
-        Ok(())
-    }
-}
@@ -5,17 +5,17 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let Some(input) = aoc_core::bench_input!("input1.txt") else {
+        return;
+    };
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
+fn part2(bencher: divan::Bencher) {
+    let Some(input) = aoc_core::bench_input!("input2.txt") else {
+        return;
+    };
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
 }
@@ -1,21 +1,13 @@
+use crate::{Model, Puzzle};
+use aoc_core::Solution;
 use miette::Result;
 
-#[tracing::instrument]
-pub fn process(input: &str) -> Result<usize> {
+pub(crate) fn solve(model: &Model) -> Result<usize> {
     todo!("day xx - part 1");
     Ok(0)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "";
-        todo!("haven't built test yet");
-        let result = 0;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
+#[tracing::instrument]
+pub fn process(input: &str) -> Result<usize> {
+    Puzzle::part1(&Puzzle::parse(input)?)
 }
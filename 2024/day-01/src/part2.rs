@@ -25,23 +25,3 @@ pub fn process(input: &str) -> Result<usize> {
     };
     Ok(sum)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "
-3   4
-4   3
-2   5
-1   3
-3   9
-3   3
-";
-        let result = 31;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
@@ -36,22 +36,3 @@ pub fn process(input: &str) -> Result<usize> {
         .count();
     Ok(sum)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9
-";
-        let result = 2;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
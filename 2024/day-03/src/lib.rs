@@ -1,2 +1,6 @@
 pub mod part1;
-pub mod part2;
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => crate::part1::process,
+}
@@ -47,16 +47,3 @@ pub fn process(input: &str) -> Result<usize> {
         .sum();
     Ok(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-        let result = 161;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
@@ -2,3 +2,9 @@ mod grid;
 pub mod part1;
 pub mod part2;
 mod vec;
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => crate::part1::process,
+    part2 => crate::part2::process,
+}
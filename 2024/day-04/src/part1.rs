@@ -78,25 +78,3 @@ pub fn process(input: &str) -> Result<usize> {
     let grid = Grid::from_str(input)?;
     Ok(grid.count_word("XMAS"))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
-        let result = 18;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
@@ -68,26 +68,3 @@ pub fn process(input: &str) -> Result<usize> {
     let grid = Grid::from_str(input)?;
     Ok(grid.count_word("MAS"))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "
-MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
-        let result = 9;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
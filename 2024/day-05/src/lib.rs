@@ -1,2 +1,32 @@
 pub mod part1;
 pub mod part2;
+mod queue;
+
+use aoc_core::Solution;
+use miette::Result;
+pub use queue::PrintQueue;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Model = PrintQueue;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<PrintQueue> {
+        queue::parse(input)
+    }
+
+    fn part1(queue: &PrintQueue) -> Result<usize> {
+        Ok(part1::solve(queue))
+    }
+
+    fn part2(queue: &PrintQueue) -> Result<usize> {
+        Ok(part2::solve(queue))
+    }
+}
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => crate::part1::process,
+    part2 => crate::part2::process,
+}
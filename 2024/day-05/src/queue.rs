@@ -0,0 +1,132 @@
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use miette::{Diagnostic, Result};
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum OrderingError {
+    #[error("No separator '{0}' was found")]
+    NoSeparator(char),
+    #[error("Too many values found ({0}) expected 2")]
+    TooManyValues(usize),
+    #[error("Not enough values found ({0}) expected 2")]
+    NotEnoughtValues(usize),
+    #[error("Failed to parse number")]
+    ParseError,
+}
+
+#[derive(Debug)]
+pub struct Ordering(pub usize, pub usize);
+
+impl FromStr for Ordering {
+    type Err = OrderingError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let splitter = '|';
+        if !s.contains(splitter) {
+            return Err(OrderingError::NoSeparator(splitter));
+        }
+
+        let pages = s.split(splitter).collect::<Vec<_>>();
+
+        if pages.len() > 2 {
+            return Err(OrderingError::TooManyValues(pages.len()));
+        }
+        if pages.len() < 2 {
+            return Err(OrderingError::NotEnoughtValues(pages.len()));
+        }
+
+        let x = pages[0]
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| OrderingError::ParseError)?;
+        let y = pages[1]
+            .trim()
+            .parse::<usize>()
+            .map_err(|_| OrderingError::ParseError)?;
+
+        Ok(Ordering(x, y))
+    }
+}
+
+impl Display for Ordering {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}|{}", self.0, self.1)
+    }
+}
+
+#[derive(Debug)]
+pub struct Rules(pub Vec<Ordering>);
+
+impl Rules {
+    pub fn is_pair(&self, a: &Page, b: &Page) -> bool {
+        self.0.iter().any(|Ordering(x, y)| *x == a.0 && *y == b.0)
+    }
+}
+
+impl FromStr for Rules {
+    type Err = OrderingError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let rules = s
+            .lines()
+            .map(Ordering::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Rules(rules))
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum PageError {
+    #[error("Failed to parse number")]
+    ParseError,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Page(pub usize);
+
+impl FromStr for Page {
+    type Err = PageError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let page = s.parse().map_err(|_| PageError::ParseError);
+        Ok(Page(page?))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pages(pub Vec<Page>);
+
+impl FromStr for Pages {
+    type Err = PageError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let pages = s
+            .split(',')
+            .map(Page::from_str)
+            .collect::<Result<Vec<_>, _>>();
+        Ok(Pages(pages?))
+    }
+}
+
+/// The page ordering rules and the updates to print.
+#[derive(Debug)]
+pub struct PrintQueue {
+    pub rules: Rules,
+    pub updates: Vec<Pages>,
+}
+
+pub fn parse(input: &str) -> Result<PrintQueue> {
+    let sections = input.split("\n\n").collect::<Vec<_>>();
+
+    let rules = Rules::from_str(sections.first().unwrap())?;
+    let updates = get_pages(sections.last().unwrap())?;
+
+    Ok(PrintQueue { rules, updates })
+}
+
+fn get_pages(section: &str) -> Result<Vec<Pages>> {
+    Ok(section
+        .lines()
+        .map(Pages::from_str)
+        .collect::<Result<Vec<_>, _>>()?)
+}
@@ -267,25 +267,3 @@ pub fn process(input: &str) -> Result<usize> {
         .sum();
     Ok(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
-        let result = 6;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
@@ -109,24 +109,3 @@ pub fn process(input: &str) -> Result<usize> {
         .sum();
     Ok(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20";
-        let result = 3749;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
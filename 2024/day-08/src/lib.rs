@@ -1,2 +1,8 @@
 pub mod part1;
 pub mod part2;
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => crate::part1::process,
+    part2 => crate::part2::process,
+}
@@ -241,25 +241,6 @@ mod tests {
 
     use super::*;
 
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............";
-        let result = 14;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-
     #[rstest]
     #[case(
         "..........
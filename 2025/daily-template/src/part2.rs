@@ -1,23 +1,14 @@
-use miette::miette;
+use crate::{Model, Puzzle};
+use aoc_core::Solution;
+use miette::Result;
 
-#[tracing::instrument]
-#[allow(clippy::missing_panics_doc)]
-#[allow(clippy::missing_errors_doc)]
-pub fn process(input: &str) -> miette::Result<usize> {
+pub(crate) fn solve(model: &Model) -> Result<usize> {
     todo!("day xx - part 2");
     Ok(0)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "";
-        todo!("haven't built test yet");
-        let result = 0;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
+#[tracing::instrument]
+#[allow(clippy::missing_errors_doc)]
+pub fn process(input: &str) -> Result<usize> {
+    Puzzle::part2(&Puzzle::parse(input)?)
 }
@@ -81,26 +81,3 @@ pub fn process(input: &str) -> Result<usize> {
     });
     Ok(count)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-";
-        let result = 3;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
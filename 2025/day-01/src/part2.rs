@@ -103,22 +103,51 @@ const fn count_zeros_crossed(pos: i32, amount: i32, direction: Direction) -> i32
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
 
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-";
-        let result = 6;
-        assert_eq!(process(input)?, result);
-        Ok(())
+    /// Turns the dial one click at a time, counting each stop on zero.
+    fn click_zeros(pos: i32, amount: i32, direction: Direction) -> i32 {
+        let step = match direction {
+            Direction::Left => -1,
+            Direction::Right => 1,
+        };
+        (1..=amount)
+            .filter(|click| (pos + step * click).rem_euclid(100) == 0)
+            .count()
+            .try_into()
+            .unwrap()
+    }
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        prop_oneof![Just(Direction::Left), Just(Direction::Right)]
+    }
+
+    proptest! {
+        #[test]
+        fn test_count_zeros_crossed(pos in 0..100, amount in 0..1000, direction in direction()) {
+            prop_assert_eq!(
+                count_zeros_crossed(pos, amount, direction),
+                click_zeros(pos, amount, direction)
+            );
+        }
+
+        #[test]
+        fn test_process_by_clicks(rotations in prop::collection::vec((direction(), 1..1000i32), 1..50)) {
+            let input = rotations
+                .iter()
+                .map(|(direction, amount)| match direction {
+                    Direction::Left => format!("L{amount}\n"),
+                    Direction::Right => format!("R{amount}\n"),
+                })
+                .collect::<String>();
+            let (_, expected) = rotations.iter().fold((50, 0), |(dial, total), &(direction, amount)| {
+                let turned = match direction {
+                    Direction::Left => dial - amount,
+                    Direction::Right => dial + amount,
+                };
+                (turned.rem_euclid(100), total + click_zeros(dial, amount, direction))
+            });
+            prop_assert_eq!(process(&input).unwrap(), usize::try_from(expected).unwrap());
+        }
     }
 }
@@ -12,8 +12,9 @@ impl FromStr for Id {
     }
 }
 
+// public for the fuzz target, which can't walk every id of a range
 #[derive(Debug)]
-struct Range {
+pub struct Range {
     start: Id,
     end: Id,
 }
@@ -72,14 +73,6 @@ mod tests {
     use super::*;
     use rstest::rstest;
 
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        let result = 1_227_775_554;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-
     #[rstest]
     #[case(11)]
     #[case(22)]
@@ -69,14 +69,6 @@ mod tests {
     use super::*;
     use rstest::rstest;
 
-    #[test]
-    fn test_process() -> Result<()> {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        let result = 4_174_379_265;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-
     #[rstest]
     #[case(11)]
     #[case(22)]
@@ -5,8 +5,8 @@ use miette::{Context, Result};
 fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+    let file = aoc_core::input!("input2.txt")?;
+    let result = process(&file).context("process part 2")?;
     println!("{result}");
     Ok(())
 }
@@ -71,20 +71,3 @@ pub fn process(input: &str) -> miette::Result<usize> {
         .sum();
     Ok(banks)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "987654321111111
-811111111111119
-234234234234278
-818181911112111
-";
-        let result = 357;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
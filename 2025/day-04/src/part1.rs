@@ -110,25 +110,3 @@ pub fn process(input: &str) -> miette::Result<usize> {
     let grid = Grid::from_str(input)?;
     Ok(grid.find_accessible())
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.
-";
-        let result = 13;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
@@ -122,25 +122,3 @@ pub fn process(input: &str) -> miette::Result<usize> {
     let mut grid = Grid::from_str(input)?;
     Ok(grid.find_accessible())
 }
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "..@@.@@@@.
-@@@.@.@.@@
-@@@@@.@.@@
-@.@@@@..@.
-@@.@@@@.@@
-.@@@@@@@.@
-.@.@.@.@@@
-@.@@@.@@@@
-.@@@@@@@@.
-@.@.@@@.@.
-";
-        let result = 43;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
@@ -0,0 +1,65 @@
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Id(pub(crate) usize);
+
+impl From<usize> for Id {
+    fn from(value: usize) -> Self {
+        Self(value)
+    }
+}
+
+impl FromStr for Id {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let num = s.trim().parse::<usize>().map_err(|e| e.to_string())?;
+        Ok(Self(num))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Range {
+    pub(crate) start: Id,
+    pub(crate) end: Id,
+}
+
+impl Range {
+    pub fn contains(&self, x: Id) -> bool {
+        x >= self.start && x <= self.end
+    }
+}
+
+impl FromStr for Range {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start_str, end_str) = s.trim().split_once('-').ok_or("`-` not found")?;
+        let start = start_str.parse::<Id>()?;
+        let end = end_str.parse::<Id>()?;
+        Ok(Self { start, end })
+    }
+}
+
+/// The ingredient database: fresh ID ranges and the available IDs.
+#[derive(Debug, Clone)]
+pub struct DB {
+    pub(crate) ranges: Vec<Range>,
+    pub(crate) ids: Vec<Id>,
+}
+
+impl FromStr for DB {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ranges_section, ids_section) = s
+            .split_once("\n\n")
+            .ok_or("No blank line separator found")?;
+        let ranges = ranges_section
+            .lines()
+            .map(Range::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        let ids = ids_section
+            .lines()
+            .map(Id::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { ranges, ids })
+    }
+}
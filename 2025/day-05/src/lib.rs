@@ -1,2 +1,32 @@
+mod db;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::Solution;
+pub use db::DB;
+use miette::{Result, miette};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Model = DB;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<DB> {
+        input.parse().map_err(|e| miette!("{e}"))
+    }
+
+    fn part1(db: &DB) -> Result<usize> {
+        Ok(db.count_fresh())
+    }
+
+    fn part2(db: &DB) -> Result<usize> {
+        Ok(db.count_range_ids())
+    }
+}
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => crate::part1::process,
+    part2 => crate::part2::process,
+}
@@ -1,45 +1,8 @@
-use std::str::FromStr;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-struct Id(usize);
-
-impl From<usize> for Id {
-    fn from(value: usize) -> Self {
-        Self(value)
-    }
-}
-
-impl FromStr for Id {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let num = s.trim().parse::<usize>().map_err(|e| e.to_string())?;
-        Ok(Self(num))
-    }
-}
-
-#[derive(Debug, Clone, Copy)]
-struct Range {
-    start: Id,
-    end: Id,
-}
-
-impl FromStr for Range {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start_str, end_str) = s.trim().split_once('-').ok_or("`-` not found")?;
-        let start = start_str.parse::<Id>()?;
-        let end = end_str.parse::<Id>()?;
-        Ok(Self { start, end })
-    }
-}
-
-#[derive(Debug, Clone)]
-struct DB {
-    ranges: Vec<Range>,
-}
+use crate::{Puzzle, db::DB};
+use aoc_core::Solution;
 
 impl DB {
-    fn count_range_ids(&self) -> usize {
+    pub(crate) fn count_range_ids(&self) -> usize {
         let mut sorted_ranges = self.ranges.clone();
         sorted_ranges.sort_by_key(|r| r.start.0);
 
@@ -60,48 +23,32 @@ impl DB {
     }
 }
 
-impl FromStr for DB {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (ranges_section, _) = s
-            .split_once("\n\n")
-            .ok_or("No blank line separator found")?;
-        let ranges = ranges_section
-            .lines()
-            .map(Range::from_str)
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(Self { ranges })
-    }
-}
-
 #[tracing::instrument]
-#[allow(clippy::missing_panics_doc)]
 #[allow(clippy::missing_errors_doc)]
 pub fn process(input: &str) -> miette::Result<usize> {
-    let db = DB::from_str(input).unwrap();
-    Ok(db.count_range_ids())
+    Puzzle::part2(&Puzzle::parse(input)?)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "3-5
-10-14
-16-20
-12-18
-
-1
-5
-8
-11
-17
-32
-";
-        let result = 14;
-        assert_eq!(process(input)?, result);
-        Ok(())
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    proptest! {
+        #[test]
+        fn test_count_range_ids(ranges in prop::collection::vec((0..200usize, 0..40usize), 1..20)) {
+            let input = ranges
+                .iter()
+                .map(|(start, len)| format!("{start}-{}", start + len))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let db = Puzzle::parse(&format!("{input}\n\n1\n")).unwrap();
+            let ids = ranges
+                .iter()
+                .flat_map(|&(start, len)| start..=start + len)
+                .collect::<BTreeSet<_>>();
+            prop_assert_eq!(db.count_range_ids(), ids.len());
+        }
     }
 }
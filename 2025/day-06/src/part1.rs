@@ -84,19 +84,3 @@ pub fn process(input: &str) -> miette::Result<usize> {
         .sum();
     Ok(result)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "123 328  51 64 
- 45 64  387 23 
-  6 98  215 314
-*   +   *   +  ";
-        let result = 4_277_556;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
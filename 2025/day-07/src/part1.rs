@@ -61,32 +61,3 @@ pub fn process(input: &str) -> miette::Result<usize> {
 
     Ok(state.splitters.len())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............
-";
-        let result = 21;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
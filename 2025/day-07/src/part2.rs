@@ -51,32 +51,3 @@ pub fn process(input: &str) -> miette::Result<usize> {
     });
     Ok(final_state.total_paths())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = ".......S.......
-...............
-.......^.......
-...............
-......^.^......
-...............
-.....^.^.^.....
-...............
-....^.^...^....
-...............
-...^.^...^.^...
-...............
-..^...^.....^..
-...............
-.^.^.^.^.^...^.
-...............
-";
-        let result = 40;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
@@ -1,15 +1,22 @@
-use day_08::{part1, part2};
+use aoc_core::Params as _;
+use day_08::{Params, part1, part2};
 
 fn main() {
     divan::main();
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!("../input1.txt")), 1000).unwrap();
+fn part1(bencher: divan::Bencher) {
+    let Some(input) = aoc_core::bench_input!("input1.txt") else {
+        return;
+    };
+    bencher.bench(|| part1::process(divan::black_box(&input), Params::real()).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!("../input2.txt"))).unwrap();
+fn part2(bencher: divan::Bencher) {
+    let Some(input) = aoc_core::bench_input!("input2.txt") else {
+        return;
+    };
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
 }
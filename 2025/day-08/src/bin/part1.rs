@@ -1,12 +1,13 @@
-use day_08::part1::process;
+use aoc_core::Params as _;
+use day_08::{Params, part1::process};
 use miette::{Context, Result};
 
 #[tracing::instrument]
 fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input1.txt");
-    let result = process(file, 1000).context("process part 1")?;
+    let file = aoc_core::input!("input1.txt")?;
+    let result = process(&file, Params::real()).context("process part 1")?;
     println!("{result}");
     Ok(())
 }
@@ -0,0 +1,40 @@
+use glam::Vec3;
+use std::str::FromStr;
+
+fn vec3_from_str(s: &str) -> Result<Vec3, String> {
+    let coords = s
+        .trim()
+        .split(',')
+        .map(|x| {
+            x.parse::<f32>()
+                .map_err(|_| "Invalid coordinate value".to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if coords.len() == 3 {
+        let vec = Vec3::new(coords[0], coords[1], coords[2]);
+        return Ok(vec);
+    }
+
+    Err("Expected exactly 3 coordinates".to_string())
+}
+
+#[derive(Debug, Clone)]
+pub struct Coordinates(pub(crate) Vec<Vec3>);
+
+impl Coordinates {
+    #[must_use]
+    pub fn positions(&self) -> &[Vec3] {
+        &self.0
+    }
+}
+
+impl FromStr for Coordinates {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coords = s
+            .lines()
+            .map(vec3_from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(coords))
+    }
+}
@@ -1,2 +1,29 @@
+mod coordinates;
 pub mod part1;
 pub mod part2;
+
+use aoc_core::Preset;
+pub use coordinates::Coordinates;
+
+/// The example connects the 10 closest pairs of junction boxes, the real
+/// input the 1000 closest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub connections: usize,
+}
+
+impl aoc_core::Params for Params {
+    fn preset(preset: Preset) -> Self {
+        let connections = match preset {
+            Preset::Example => 10,
+            Preset::Real => 1000,
+        };
+        Self { connections }
+    }
+}
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => |input| crate::part1::process(input, aoc_core::Params::example()),
+    part2 => crate::part2::process,
+}
@@ -1,24 +1,7 @@
-use glam::Vec3;
+use crate::{Coordinates, Params};
 use miette::miette;
 use std::{collections::HashSet, str::FromStr};
 
-fn vec3_from_str(s: &str) -> Result<Vec3, String> {
-    let coords = s
-        .trim()
-        .split(',')
-        .map(|x| {
-            x.parse::<f32>()
-                .map_err(|_| "Invalid coordinate value".to_string())
-        })
-        .collect::<Result<Vec<_>, _>>()?;
-    if coords.len() == 3 {
-        let vec = Vec3::new(coords[0], coords[1], coords[2]);
-        return Ok(vec);
-    }
-
-    Err("Expected exactly 3 coordinates".to_string())
-}
-
 #[derive(Debug, Clone)]
 struct UnionFind {
     parent: Vec<usize>,
@@ -54,10 +37,12 @@ impl UnionFind {
     }
 }
 
-#[derive(Debug, Clone)]
-struct Coordinates(Vec<Vec3>);
+// `Coordinates` is shared with part 2, which solves it its own way
+trait Solve {
+    fn solve(&self, size: usize) -> usize;
+}
 
-impl Coordinates {
+impl Solve for Coordinates {
     fn solve(&self, size: usize) -> usize {
         let n = self.0.len();
 
@@ -90,53 +75,10 @@ impl Coordinates {
     }
 }
 
-impl FromStr for Coordinates {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let coords = s
-            .lines()
-            .map(vec3_from_str)
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(Self(coords))
-    }
-}
-
 #[tracing::instrument]
 #[allow(clippy::missing_panics_doc)]
 #[allow(clippy::missing_errors_doc)]
-pub fn process(input: &str, size: usize) -> miette::Result<usize> {
+pub fn process(input: &str, params: Params) -> miette::Result<usize> {
     let coords = Coordinates::from_str(input).map_err(|e| miette!("{e}"))?;
-    Ok(coords.solve(size))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "162,817,812
-57,618,57
-906,360,560
-592,479,940
-352,342,300
-466,668,158
-542,29,236
-431,825,988
-739,650,466
-52,470,668
-216,146,977
-819,987,18
-117,168,530
-805,96,715
-346,949,466
-970,615,88
-941,993,340
-862,61,35
-984,92,344
-425,690,689";
-        let result = 40;
-        assert_eq!(process(input, 10)?, result);
-        Ok(())
-    }
+    Ok(coords.solve(params.connections))
 }
@@ -5,8 +5,8 @@ use miette::{Context, Result};
 fn main() -> Result<()> {
     tracing_subscriber::fmt::init();
 
-    let file = include_str!("../../input1.txt");
-    let result = process(file).context("process part 1")?;
+    let file = aoc_core::input!("input1.txt")?;
+    let result = process(&file).context("process part 1")?;
     println!("{result}");
     Ok(())
 }
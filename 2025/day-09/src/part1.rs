@@ -71,23 +71,3 @@ pub fn process(input: &str) -> miette::Result<usize> {
     let grid = Grid::from_str(input).map_err(|e| miette!("{e}"))?;
     Ok(grid.get_max_area())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "7,1
-11,1
-11,7
-9,7
-9,5
-2,5
-2,3
-7,3";
-        let result = 50;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
@@ -0,0 +1,101 @@
+use aoc_core::{
+    Part,
+    generate::{Generate, Generated, Rng, Seeded},
+};
+use itertools::Itertools;
+
+/// Machines whose fewest presses are known by construction.
+///
+/// The buttons of a machine are wired independently of each other: no set of
+/// them toggles the same lights as another. So exactly one set of buttons
+/// lights the indicator, and the fewest presses are its size.
+pub struct Machines;
+
+impl Generate for Machines {
+    /// `size` machines of three to ten lights. Part 2 isn't solved yet, so its
+    /// answer stays unknown, though the joltages are reachable.
+    fn generate(rng: &mut Seeded, size: usize) -> Generated {
+        let (lines, presses): (Vec<_>, Vec<_>) = (0..size).map(|_| machine(rng)).unzip();
+        Generated::new(lines.join("\n") + "\n")
+            .with_answer(Part::One, presses.iter().sum::<usize>())
+    }
+}
+
+/// A machine and the fewest presses lighting its indicator.
+fn machine(rng: &mut Seeded) -> (String, usize) {
+    let lights = rng.gen_range(3..=10);
+    let count = rng.gen_range(2..=lights);
+    let mut buttons = Vec::<u16>::new();
+    let mut basis = Vec::<u16>::new();
+    while buttons.len() < count {
+        let wiring = rng.gen_range(1..1 << lights);
+        // xor out the basis; what's left is the part no set of buttons reaches
+        let rest = basis
+            .iter()
+            .fold(wiring, |rest, &vector| rest.min(rest ^ vector));
+        if rest != 0 {
+            basis.push(rest);
+            basis.sort_unstable_by(|a, b| b.cmp(a));
+            buttons.push(wiring);
+        }
+    }
+
+    let pressed = rng.gen_range(1..1_u32 << count);
+    let indicator = (0..count)
+        .filter(|i| pressed & 1 << i != 0)
+        .fold(0, |state, i| state ^ buttons[i]);
+    let joltage = {
+        let counts = buttons.iter().map(|_| rng.gen_range(0..10)).collect_vec();
+        (0..lights)
+            .map(|light| {
+                buttons
+                    .iter()
+                    .zip(&counts)
+                    .filter(|(wiring, _)| *wiring & 1 << light != 0)
+                    .map(|(_, count)| count)
+                    .sum::<u32>()
+            })
+            .join(",")
+    };
+
+    let indicator = (0..lights)
+        .map(|light| {
+            if indicator & 1 << light == 0 {
+                '.'
+            } else {
+                '#'
+            }
+        })
+        .collect::<String>();
+    let buttons = buttons
+        .iter()
+        .map(|wiring| {
+            let wired = (0..lights).filter(|light| wiring & 1 << light != 0);
+            format!("({})", wired.format(","))
+        })
+        .join(" ");
+    let line = format!("[{indicator}] {buttons} {{{joltage}}}");
+    (line, pressed.count_ones() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1;
+    use aoc_core::Answer;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    fn test_generated(#[case] seed: u64) -> miette::Result<()> {
+        let case = Machines::seeded(seed, 50);
+        assert_eq!(case.input.lines().count(), 50);
+        assert_eq!(
+            case.answer(Part::One),
+            Some(&Answer::from(part1::process(&case.input)?))
+        );
+        Ok(())
+    }
+}
@@ -1,2 +1,11 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+
+pub use generate::Machines;
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => crate::part1::process,
+    part2 => crate::part2::process,
+}
@@ -1,15 +1,34 @@
-use day_11::{part1, part2};
+use aoc_core::generate::Generate;
+use day_11::{Rack, part1, part2};
 
 fn main() {
     divan::main();
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!("../input1.txt"))).unwrap();
+fn part1(bencher: divan::Bencher) {
+    let Some(input) = aoc_core::bench_input!("input1.txt") else {
+        return;
+    };
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!("../input2.txt"))).unwrap();
+fn part2(bencher: divan::Bencher) {
+    let Some(input) = aoc_core::bench_input!("input2.txt") else {
+        return;
+    };
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench(args = [100, 600])]
+fn part1_generated(bencher: divan::Bencher, size: usize) {
+    let input = Rack::seeded(0, size).input;
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench(args = [100, 600])]
+fn part2_generated(bencher: divan::Bencher, size: usize) {
+    let input = Rack::seeded(0, size).input;
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
 }
@@ -0,0 +1,128 @@
+use crate::{Name, Rack};
+use aoc_core::{
+    Part,
+    generate::{Generate, Generated, Rng, Seeded, SliceRandom},
+};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+/// How far ahead of `you` the rack ends, which keeps the part 1 paths few
+/// enough to walk one by one, as in the real input.
+const TAIL: usize = 16;
+
+impl Generate for Rack {
+    /// A rack of `size` devices besides `out`, wired without cycles.
+    ///
+    /// The devices are laid out in a row, each feeding the next and, now and
+    /// then, one a few further down, so the paths are counted in one sweep
+    /// from the end. Those skips get rarer the longer the row, keeping the
+    /// paths about as many as in the real input.
+    ///
+    /// `svr` heads the row, `dac` and `fft` sit somewhere in it and `you` is
+    /// near its end. Answers too large for a `usize` are left unknown.
+    fn generate(rng: &mut Seeded, size: usize) -> Generated {
+        let size = size.max(TAIL + 3);
+        let mut names = HashSet::from([Name::YOU, Name::OUT, Name::DAC, Name::FFT, Name::SVR]);
+        let mut row = vec![Name::SVR];
+        while row.len() < size - 3 {
+            let name = Name::new(std::array::from_fn(|_| rng.gen_range('a'..='z')));
+            if names.insert(name) {
+                row.push(name);
+            }
+        }
+        row.insert(size - TAIL, Name::YOU);
+        for name in [Name::DAC, Name::FFT] {
+            row.insert(rng.gen_range(1..size - TAIL), name);
+        }
+        row.push(Name::OUT);
+
+        // one in four, or 40 skips overall in longer rows
+        let skip = u32::try_from(size).unwrap_or(u32::MAX).max(160);
+        let outputs = (0..size)
+            .map(|i| {
+                let mut outputs = vec![i + 1];
+                if rng.gen_ratio(40, skip) {
+                    let further = rng.gen_range(i + 2..=i + 4);
+                    outputs.push(further.min(size));
+                }
+                outputs.dedup();
+                outputs
+            })
+            .collect_vec();
+
+        let mut lines = (0..size)
+            .map(|i| {
+                format!(
+                    "{}: {}",
+                    row[i],
+                    outputs[i].iter().map(|&j| row[j]).join(" ")
+                )
+            })
+            .collect_vec();
+        lines.shuffle(rng);
+
+        let position = |name| row.iter().position(|&n| n == name).unwrap_or(size);
+        let paths = |from, to| paths(&outputs, position(from), position(to));
+        let part1 = paths(Name::YOU, Name::OUT);
+        let part2 = [[Name::DAC, Name::FFT], [Name::FFT, Name::DAC]]
+            .into_iter()
+            .map(|[first, second]| {
+                paths(Name::SVR, first)?
+                    .checked_mul(paths(first, second)?)?
+                    .checked_mul(paths(second, Name::OUT)?)
+            })
+            .try_fold(0_usize, |total, paths| total.checked_add(paths?));
+
+        let mut generated = Generated::new(lines.join("\n") + "\n");
+        if let Some(part1) = part1 {
+            generated = generated.with_answer(Part::One, part1);
+        }
+        if let Some(part2) = part2 {
+            generated = generated.with_answer(Part::Two, part2);
+        }
+        generated
+    }
+}
+
+/// Number of paths from device `from` to device `to` of the row, `None` when
+/// it overflows.
+fn paths(outputs: &[Vec<usize>], from: usize, to: usize) -> Option<usize> {
+    if from > to {
+        return Some(0);
+    }
+    let mut counts = vec![Some(0); to + 1];
+    counts[to] = Some(1);
+    for i in (from..to).rev() {
+        counts[i] = outputs[i]
+            .iter()
+            .filter(|&&j| j <= to)
+            .try_fold(0_usize, |total, &j| total.checked_add(counts[j]?));
+    }
+    counts.get(from).copied().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+    use aoc_core::Answer;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 30)]
+    #[case(2, 100)]
+    #[case(3, 600)]
+    fn test_generated(#[case] seed: u64, #[case] size: usize) -> miette::Result<()> {
+        let case = Rack::seeded(seed, size);
+        assert_eq!(case.input.lines().count(), size);
+        assert_eq!(
+            case.answer(Part::One),
+            Some(&Answer::from(part1::process(&case.input)?))
+        );
+        assert_eq!(
+            case.answer(Part::Two),
+            Some(&Answer::from(part2::process(&case.input)?))
+        );
+        Ok(())
+    }
+}
@@ -1,2 +1,12 @@
+mod generate;
 pub mod part1;
 pub mod part2;
+mod rack;
+
+pub use rack::{Device, Name, Rack};
+
+#[cfg(test)]
+aoc_core::example_tests! {
+    part1 => crate::part1::process,
+    part2 => crate::part2::process,
+}
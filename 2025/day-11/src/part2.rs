@@ -1,71 +1,16 @@
-use itertools::Itertools;
+use crate::{Device, Name, Rack};
 use miette::miette;
 use std::{
     collections::{HashMap, HashSet},
     str::FromStr,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Name([char; 3]);
-
-impl Name {
-    const OUT: Self = Self(['o', 'u', 't']);
-    const DAC: Self = Self(['d', 'a', 'c']);
-    const FFT: Self = Self(['f', 'f', 't']);
-    const SVR: Self = Self(['s', 'v', 'r']);
-}
-
-impl FromStr for Name {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let chars = s.trim().chars().collect_vec();
-        if chars.len() != 3 {
-            return Err(format!(
-                "Name must be exactly 3 characters, got {}",
-                chars.len()
-            ));
-        }
-        Ok(Self([chars[0], chars[1], chars[2]]))
-    }
-}
-
-#[derive(Debug, Clone)]
-struct Device {
-    input: Name,
-    outputs: Vec<Name>,
-}
-
-impl FromStr for Device {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let trimmed = s.trim();
-        let (input_str, output_str) = trimmed.split_once(':').ok_or("Missing `:`")?;
-
-        Ok(Self {
-            input: Name::from_str(input_str)?,
-            outputs: output_str
-                .split_whitespace()
-                .map(Name::from_str)
-                .collect::<Result<Vec<_>, _>>()?,
-        })
-    }
-}
-
-#[derive(Debug, Clone)]
-struct Rack(Vec<Device>);
-
-impl FromStr for Rack {
-    type Err = String;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let rack = s
-            .lines()
-            .map(Device::from_str)
-            .collect::<Result<Vec<_>, _>>()?;
-        Ok(Self(rack))
-    }
+// `Rack` is shared with part 1, which solves it its own way
+trait Solve {
+    fn solve(&self) -> usize;
 }
 
-impl Rack {
+impl Solve for Rack {
     fn solve(&self) -> usize {
         let mut memo = HashMap::new();
         let mut computing = HashSet::new();
@@ -132,28 +77,3 @@ pub fn process(input: &str) -> miette::Result<usize> {
     let rack = Rack::from_str(input).map_err(|e| miette!("{e}"))?;
     Ok(rack.solve())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_process() -> miette::Result<()> {
-        let input = "svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out";
-        let result = 2;
-        assert_eq!(process(input)?, result);
-        Ok(())
-    }
-}
@@ -0,0 +1,93 @@
+use itertools::Itertools;
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Name([char; 3]);
+
+impl Name {
+    pub(crate) const YOU: Self = Self(['y', 'o', 'u']);
+    pub(crate) const OUT: Self = Self(['o', 'u', 't']);
+    pub(crate) const DAC: Self = Self(['d', 'a', 'c']);
+    pub(crate) const FFT: Self = Self(['f', 'f', 't']);
+    pub(crate) const SVR: Self = Self(['s', 'v', 'r']);
+
+    #[must_use]
+    pub const fn new(chars: [char; 3]) -> Self {
+        Self(chars)
+    }
+}
+
+impl FromStr for Name {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars = s.trim().chars().collect_vec();
+        if chars.len() != 3 {
+            return Err(format!(
+                "Name must be exactly 3 characters, got {}",
+                chars.len()
+            ));
+        }
+        Ok(Self([chars[0], chars[1], chars[2]]))
+    }
+}
+
+impl Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|ch| write!(f, "{ch}"))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Device {
+    pub(crate) input: Name,
+    pub(crate) outputs: Vec<Name>,
+}
+
+impl Device {
+    #[must_use]
+    pub const fn input(&self) -> Name {
+        self.input
+    }
+
+    #[must_use]
+    pub fn outputs(&self) -> &[Name] {
+        &self.outputs
+    }
+}
+
+impl FromStr for Device {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let (input_str, output_str) = trimmed.split_once(':').ok_or("Missing `:`")?;
+
+        Ok(Self {
+            input: Name::from_str(input_str)?,
+            outputs: output_str
+                .split_whitespace()
+                .map(Name::from_str)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rack(pub(crate) Vec<Device>);
+
+impl Rack {
+    #[must_use]
+    pub fn devices(&self) -> &[Device] {
+        &self.0
+    }
+}
+
+impl FromStr for Rack {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rack = s
+            .lines()
+            .map(Device::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self(rack))
+    }
+}
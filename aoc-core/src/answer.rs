@@ -0,0 +1,157 @@
+//! One answer type for every part, whatever it returns.
+//!
+//! Parts return whatever integer width fits the puzzle, some return text such
+//! as a comma separated list and some draw letters on a grid. [`Answer`]
+//! holds any of them without losing information, so the runner, its cache and
+//! the verifier compare them without caring which one they got.
+
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Answer {
+    /// Any integer up to 64 bits and every `i128`.
+    Int(i128),
+    /// A `u128` too large for [`Answer::Int`].
+    UInt(u128),
+    Text(String),
+    /// Rows of letters drawn on a grid, without trailing whitespace.
+    Grid(String),
+}
+
+impl Answer {
+    /// An answer drawn across `rows`.
+    #[must_use]
+    pub fn grid<R: AsRef<str>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.as_ref().trim_end().to_string())
+            .collect::<Vec<_>>();
+        let start = rows.iter().position(|row| !row.is_empty()).unwrap_or(0);
+        let end = rows
+            .iter()
+            .rposition(|row| !row.is_empty())
+            .map_or(start, |end| end + 1);
+        Self::Grid(rows[start..end].join("\n"))
+    }
+
+    /// An answer drawn with lit (`#`) and dark (`.`) pixels.
+    #[must_use]
+    pub fn pixels<R: AsRef<[bool]>>(rows: &[R]) -> Self {
+        Self::grid(rows.iter().map(|row| {
+            row.as_ref()
+                .iter()
+                .map(|&lit| if lit { '#' } else { '.' })
+                .collect::<String>()
+        }))
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {$(
+        impl From<$int> for Answer {
+            fn from(value: $int) -> Self {
+                Self::Int(i128::from(value))
+            }
+        }
+    )*};
+}
+
+from_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Self::Int(value as i128)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Self::Int(value as i128)
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        i128::try_from(value).map_or(Self::UInt(value), Self::Int)
+    }
+}
+
+/// Text that reads back exactly as a number becomes that number, several lines
+/// become a grid, anything else stays text.
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        if let Ok(int) = text.parse::<i128>()
+            && int.to_string() == text
+        {
+            return Self::Int(int);
+        }
+        if let Ok(uint) = text.parse::<u128>()
+            && uint.to_string() == text
+        {
+            return Self::UInt(uint);
+        }
+        if text.trim().contains('\n') {
+            Self::grid(text.lines())
+        } else {
+            Self::Text(text)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Self::from(text.to_string())
+    }
+}
+
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Int(int) => write!(f, "{int}"),
+            Self::UInt(uint) => write!(f, "{uint}"),
+            Self::Text(text) | Self::Grid(text) => f.write_str(text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(Answer::from(7_u32), Answer::from(7_i64));
+        assert_eq!(Answer::from(7_usize), Answer::Int(7));
+        assert_eq!(Answer::from(-3_i64).to_string(), "-3");
+        assert_eq!(Answer::from(u128::MAX), Answer::UInt(u128::MAX));
+        assert_eq!(Answer::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!(Answer::from("55413"), Answer::Int(55413));
+        assert_eq!(Answer::from("007"), Answer::Text("007".to_string()));
+        assert_eq!(
+            Answer::from("3,5,7".to_string()),
+            Answer::Text("3,5,7".to_string())
+        );
+        assert_eq!("abc".parse(), Ok(Answer::Text("abc".to_string())));
+    }
+
+    #[test]
+    fn test_grid() {
+        let drawn = Answer::pixels(&[[true, false, true], [true, true, false]]);
+        assert_eq!(drawn.to_string(), "#.#\n##.");
+        assert_eq!(Answer::from("\n#.#  \n##.\n"), drawn);
+        assert_eq!(Answer::grid([".#", ""]), Answer::Grid(".#".to_string()));
+    }
+}
@@ -0,0 +1,195 @@
+//! The worked examples of a puzzle, kept as fixture files in the `examples/`
+//! directory next to a day's manifest.
+//!
+//! Every `<name>.txt` is an example input. The answer a part expects for it
+//! sits beside it as `<name>.part1` or `<name>.part2`, so an example only one
+//! part explains has only that part's answer. `aoc fetch --puzzle` extracts
+//! them from the puzzle page, and [`example_tests!`] makes a test of each.
+
+use crate::{Answer, Part};
+use std::{
+    env,
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Directory of the examples, relative to the day's manifest.
+pub const DIR: &str = "examples";
+
+/// Path of the input of the example `name`.
+#[must_use]
+pub fn input_path(dir: &Path, name: &str) -> PathBuf {
+    dir.join(format!("{name}.txt"))
+}
+
+/// Path of the answer `part` expects for the example `name`.
+#[must_use]
+pub fn answer_path(dir: &Path, name: &str, part: Part) -> PathBuf {
+    dir.join(format!("{name}.part{part}"))
+}
+
+/// Runs `process` on the example whose answer sits at `answer`, a
+/// `<name>.partN` file, and compares it to that answer.
+///
+/// # Panics
+///
+/// When the example can't be read, `process` fails or its answer differs,
+/// which is how [`example_tests!`] fails a case.
+pub fn check<A, E>(answer: &Path, process: impl FnOnce(&str) -> Result<A, E>)
+where
+    A: Into<Answer>,
+    E: std::fmt::Display,
+{
+    let read = |path: &Path| {
+        fs::read_to_string(path)
+            .unwrap_or_else(|error| panic!("failed to read {}: {error}", path.display()))
+    };
+    let input = read(&answer.with_extension("txt"));
+    let expected = Answer::from(read(answer).trim());
+    match process(&input) {
+        Ok(actual) => assert_eq!(actual.into(), expected, "{}", answer.display()),
+        Err(error) => panic!("{}: {error}", answer.display()),
+    }
+}
+
+/// Writes the cases [`example_tests!`] runs for the calling crate, one file
+/// per part in `OUT_DIR`, from its `build.rs`:
+///
+/// ```ignore
+/// fn main() -> std::io::Result<()> {
+///     aoc_core::examples::write_cases()
+/// }
+/// ```
+///
+/// # Errors
+///
+/// When `examples/` can't be listed or the cases can't be written.
+pub fn write_cases() -> io::Result<()> {
+    println!("cargo::rerun-if-changed={DIR}");
+    let var = |name| {
+        env::var_os(name)
+            .map(PathBuf::from)
+            .ok_or_else(|| io::Error::other(format!("{name} isn't set, run from build.rs")))
+    };
+    let dir = var("CARGO_MANIFEST_DIR")?.join(DIR);
+    let out = var("OUT_DIR")?;
+    for part in Part::ALL {
+        fs::write(out.join(format!("examples_part{part}.rs")), cases(&dir, part)?)?;
+    }
+    Ok(())
+}
+
+/// An rstest function with a case per example in `dir` that `part` has an
+/// answer for, nothing when there's none.
+fn cases(dir: &Path, part: Part) -> io::Result<String> {
+    let extension = format!("part{part}");
+    let mut answers = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(error) => return Err(error),
+    };
+    answers.retain(|path| path.extension().is_some_and(|ext| *ext == *extension));
+    answers.sort();
+    if answers.is_empty() {
+        return Ok(String::new());
+    }
+
+    let mut cases = String::from("#[::rstest::rstest]\n");
+    for answer in &answers {
+        let stem = answer
+            .file_stem()
+            .map(|stem| stem.to_string_lossy())
+            .unwrap_or_default();
+        let mut name = stem
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            name.insert(0, '_');
+        }
+        let _ = writeln!(cases, "#[case::{name}({:?})]", answer.to_string_lossy());
+    }
+    cases.push_str("fn cases(#[case] answer: &str) {\n    check(::std::path::Path::new(answer));\n}\n");
+    Ok(cases)
+}
+
+/// Generates a test case per example of the calling crate and part, run by
+/// rstest, so a regression case is added by dropping its `<name>.txt` and
+/// `<name>.partN` into `examples/`.
+///
+/// Name each part and how to solve it:
+///
+/// ```ignore
+/// #[cfg(test)]
+/// aoc_core::example_tests! {
+///     part1 => |input| crate::part1::process(input, Params::example()),
+///     part2 => crate::part2::process,
+/// }
+/// ```
+///
+/// The cases land in a module `examples`, one per file, e.g.
+/// `examples::part1::cases::case_1_example`. They are listed by
+/// [`write_cases`] in the crate's `build.rs`, which reruns when `examples/`
+/// changes; a part without any example has no cases yet.
+#[macro_export]
+macro_rules! example_tests {
+    ($($part:ident => $process:expr),+ $(,)?) => {
+        mod examples {
+            $(
+                mod $part {
+                    // unused until the part has an example
+                    #[allow(dead_code)]
+                    fn check(answer: &::std::path::Path) {
+                        $crate::examples::check(answer, $process);
+                    }
+
+                    include!(concat!(env!("OUT_DIR"), "/examples_", stringify!($part), ".rs"));
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let dir = env::temp_dir().join(format!("aoc-core-check-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(input_path(&dir, "example"), "1abc2\n").unwrap();
+        fs::write(answer_path(&dir, "example", Part::One), "12\n").unwrap();
+        let answer = answer_path(&dir, "example", Part::One);
+        let digits = |input: &str| input.trim().replace(char::is_alphabetic, "").parse::<u32>();
+
+        check(&answer, digits);
+        let wrong =
+            std::panic::catch_unwind(|| check(&answer, |input| digits(input).map(|n| n + 1)));
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(wrong.is_err());
+    }
+
+    #[test]
+    fn test_cases() {
+        let dir = env::temp_dir().join(format!("aoc-core-cases-{}", std::process::id()));
+        assert_eq!(cases(&dir, Part::One).unwrap(), "");
+
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["example", "range-end"] {
+            fs::write(input_path(&dir, name), "").unwrap();
+            fs::write(answer_path(&dir, name, Part::One), "1\n").unwrap();
+        }
+        let part1 = cases(&dir, Part::One).unwrap();
+        let part2 = cases(&dir, Part::Two).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(part1.contains("#[case::example("));
+        assert!(part1.contains("#[case::range_end("));
+        assert!(part1.contains("range-end.part1"));
+        assert_eq!(part2, "");
+    }
+}
@@ -0,0 +1,107 @@
+//! Synthetic puzzle inputs, for benches and tests that can't rely on the
+//! real inputs, which are never committed in plain text.
+//!
+//! A day implementing [`Generate`] emits structurally valid inputs from a
+//! seed and a size, and works out the answers alongside whenever it can do
+//! so without running its own solution, so generated cases double as tests:
+//!
+//! ```ignore
+//! let case = Machines::seeded(7, 100);
+//! assert_eq!(case.answer(Part::One), Some(&Answer::from(part1::process(&case.input)?)));
+//! ```
+//!
+//! The same seed and size give the same input on every platform and run.
+
+use crate::{Answer, Part};
+use rand::SeedableRng;
+pub use rand::{Rng, seq::SliceRandom};
+
+/// The generator behind every [`Generate`], seeded so inputs are reproducible.
+pub type Seeded = rand_chacha::ChaCha8Rng;
+
+/// A generated input and the answers known for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Generated {
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl Generated {
+    /// An input whose answers aren't known.
+    #[must_use]
+    pub const fn new(input: String) -> Self {
+        Self {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_answer(mut self, part: Part, answer: impl Into<Answer>) -> Self {
+        *self.slot(part) = Some(answer.into());
+        self
+    }
+
+    #[must_use]
+    pub const fn answer(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    const fn slot(&mut self, part: Part) -> &mut Option<Answer> {
+        match part {
+            Part::One => &mut self.part1,
+            Part::Two => &mut self.part2,
+        }
+    }
+}
+
+/// A day that can generate its own inputs.
+pub trait Generate {
+    /// Generates an input drawing from `rng`, with `size` its main dimension,
+    /// e.g. the number of machines or devices.
+    fn generate(rng: &mut Seeded, size: usize) -> Generated;
+
+    /// Generates the input of `seed` and `size`.
+    #[must_use]
+    fn seeded(seed: u64, size: usize) -> Generated {
+        Self::generate(&mut Seeded::seed_from_u64(seed), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sums;
+
+    impl Generate for Sums {
+        fn generate(rng: &mut Seeded, size: usize) -> Generated {
+            let nums = (0..size)
+                .map(|_| rng.gen_range(0..100))
+                .collect::<Vec<u32>>();
+            let input = nums.iter().map(ToString::to_string).collect::<Vec<_>>();
+            Generated::new(input.join("\n")).with_answer(Part::One, nums.iter().sum::<u32>())
+        }
+    }
+
+    #[test]
+    fn test_seeded() {
+        let case = Sums::seeded(7, 10);
+        assert_eq!(case.input.lines().count(), 10);
+        assert_eq!(Sums::seeded(7, 10), case);
+        assert_ne!(Sums::seeded(8, 10), case);
+
+        let sum = case
+            .input
+            .lines()
+            .map(|n| n.parse::<u32>().unwrap())
+            .sum::<u32>();
+        assert_eq!(case.answer(Part::One), Some(&Answer::from(sum)));
+        assert_eq!(case.answer(Part::Two), None);
+    }
+}
@@ -0,0 +1,258 @@
+//! Finding a day's puzzle input at runtime.
+//!
+//! Inputs are git-ignored, so nothing may `include_str!` them. Instead the
+//! input is resolved, in order, from:
+//!
+//! 1. an explicit path, e.g. the first command line argument of a bin;
+//! 2. the path in the `AOC_INPUT` environment variable;
+//! 3. standard input, when either of the above is `-`;
+//! 4. the default file(s) of the day, e.g. `input1.txt` next to its manifest,
+//!    falling back to the [`SHARED`] file both parts read when they share
+//!    their input, then to its encrypted copy in the [`vault`].
+//!
+//! Paths ending in `.enc` are decrypted when read.
+
+use crate::vault::{self, VaultError};
+use miette::Diagnostic;
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Environment variable overriding the default input file.
+pub const ENV: &str = "AOC_INPUT";
+
+/// The input both parts of a day fall back to, which is where `aoc fetch`
+/// saves it.
+pub const SHARED: &str = "input.txt";
+
+/// Where an input was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Path(PathBuf),
+    Env(PathBuf),
+    Stdin,
+    Default(PathBuf),
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Env(path) => write!(f, "{} (from `{ENV}`)", path.display()),
+            Self::Stdin => write!(f, "standard input"),
+            Self::Default(path) => write!(f, "{} (default)", path.display()),
+        }
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum InputError {
+    #[error("no puzzle input found, looked for {}", list(.looked_for))]
+    #[diagnostic(
+        code(aoc::input::missing),
+        help(
+            "inputs are git-ignored: fetch yours, pass its path, set `{ENV}` or pipe it in with `-`"
+        )
+    )]
+    Missing { looked_for: Vec<PathBuf> },
+    #[error("failed to read puzzle input from {origin}")]
+    #[diagnostic(code(aoc::input::read))]
+    Read {
+        origin: Source,
+        #[source]
+        error: io::Error,
+    },
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Vault(#[from] VaultError),
+}
+
+fn list(paths: &[PathBuf]) -> String {
+    paths
+        .iter()
+        .map(|path| format!("`{}`", path.display()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub text: String,
+    pub source: Source,
+}
+
+/// Resolves an input from `explicit`, then `AOC_INPUT`, then the first
+/// existing file of `defaults`.
+///
+/// # Errors
+///
+/// When no source is available or the chosen one can't be read.
+pub fn resolve(explicit: Option<&str>, defaults: &[PathBuf]) -> Result<Input, InputError> {
+    overridden(explicit).unwrap_or_else(|| from_defaults(defaults))
+}
+
+/// Reads the input chosen by `explicit` or `AOC_INPUT`, if either is set.
+///
+/// Standard input can only be read once, so callers needing the same input
+/// several times should hold on to the result.
+#[must_use]
+pub fn overridden(explicit: Option<&str>) -> Option<Result<Input, InputError>> {
+    explicit
+        .map(|path| source(path, Source::Path))
+        .or_else(|| {
+            env::var(ENV)
+                .ok()
+                .filter(|path| !path.is_empty())
+                .map(|path| source(&path, Source::Env))
+        })
+        .map(read)
+}
+
+/// Reads the first existing file of `defaults`, ignoring any override.
+///
+/// # Errors
+///
+/// When none of `defaults` exists or it can't be read.
+pub fn from_defaults(defaults: &[PathBuf]) -> Result<Input, InputError> {
+    let path = defaults
+        .iter()
+        .find(|path| path.is_file())
+        .ok_or_else(|| InputError::Missing {
+            looked_for: defaults.to_vec(),
+        })?;
+    read(Source::Default(path.clone()))
+}
+
+/// Resolves the input of a bin, taking the first command line argument as
+/// the explicit path. See [`input!`](crate::input!).
+///
+/// # Errors
+///
+/// See [`resolve`].
+pub fn load(default: impl AsRef<Path>) -> Result<String, InputError> {
+    let explicit = env::args().nth(1);
+    resolve(explicit.as_deref(), &with_shared(default.as_ref())).map(|input| input.text)
+}
+
+/// Resolves the input of a benchmark, which can't take a path argument.
+///
+/// A missing input is reported and yields `None` so the benchmark is skipped
+/// instead of failing. See [`bench_input!`](crate::bench_input!).
+#[must_use]
+pub fn for_bench(default: impl AsRef<Path>) -> Option<String> {
+    match resolve(None, &with_shared(default.as_ref())) {
+        Ok(input) => Some(input.text),
+        Err(err) => {
+            eprintln!("skipping benchmark: {err}");
+            None
+        }
+    }
+}
+
+/// `default` followed by the [`SHARED`] input next to it and its encrypted
+/// copy.
+fn with_shared(default: &Path) -> Vec<PathBuf> {
+    let shared = default.with_file_name(SHARED);
+    let mut paths = vec![default.to_path_buf()];
+    if shared != default {
+        paths.push(shared);
+    }
+    paths.extend(default.parent().and_then(vault::input_path));
+    paths
+}
+
+fn source(path: &str, kind: fn(PathBuf) -> Source) -> Source {
+    if path == "-" {
+        Source::Stdin
+    } else {
+        kind(PathBuf::from(path))
+    }
+}
+
+fn read(source: Source) -> Result<Input, InputError> {
+    if let Source::Path(path) | Source::Env(path) | Source::Default(path) = &source
+        && vault::is_encrypted(path)
+        && path.is_file()
+    {
+        let text = vault::read(path)?;
+        return Ok(Input { text, source });
+    }
+    let text = match &source {
+        Source::Stdin => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).map(|_| text)
+        }
+        Source::Path(path) | Source::Env(path) | Source::Default(path) => fs::read_to_string(path),
+    };
+    match text {
+        Ok(text) => Ok(Input { text, source }),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => match source {
+            Source::Path(path) | Source::Env(path) | Source::Default(path) => {
+                Err(InputError::Missing {
+                    looked_for: vec![path],
+                })
+            }
+            Source::Stdin => Err(InputError::Read {
+                origin: source,
+                error,
+            }),
+        },
+        Err(error) => Err(InputError::Read {
+            origin: source,
+            error,
+        }),
+    }
+}
+
+/// Loads a day's input at runtime, defaulting to `file` next to the calling
+/// crate's manifest.
+///
+/// ```ignore
+/// let file = aoc_core::input!("input1.txt")?;
+/// ```
+#[macro_export]
+macro_rules! input {
+    ($file:literal) => {
+        $crate::input::load(concat!(env!("CARGO_MANIFEST_DIR"), "/", $file))
+    };
+}
+
+/// Like [`input!`] but for benchmarks: `None` when the input is missing.
+#[macro_export]
+macro_rules! bench_input {
+    ($file:literal) => {
+        $crate::input::for_bench(concat!(env!("CARGO_MANIFEST_DIR"), "/", $file))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() -> Result<(), InputError> {
+        let dir = env::temp_dir().join(format!("aoc-core-input-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let missing = dir.join("input1.txt");
+        let present = dir.join("input.txt");
+        fs::write(&present, "1abc2\n").unwrap();
+
+        let input = from_defaults(&[missing.clone(), present.clone()])?;
+        assert_eq!(input.text, "1abc2\n");
+        assert_eq!(input.source, Source::Default(present.clone()));
+
+        let explicit = resolve(present.to_str(), std::slice::from_ref(&missing))?;
+        assert_eq!(explicit.source, Source::Path(present));
+
+        let err = from_defaults(std::slice::from_ref(&missing)).unwrap_err();
+        assert!(matches!(err, InputError::Missing { looked_for } if looked_for == [missing]));
+
+        fs::remove_dir_all(dir).unwrap();
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+//! Building blocks shared by the solutions of every year and the `aoc` runner.
+
+mod answer;
+pub mod examples;
+#[cfg(feature = "generate")]
+pub mod generate;
+pub mod input;
+pub mod params;
+mod part;
+pub mod solution;
+pub mod vault;
+
+pub use answer::Answer;
+pub use params::{Params, Preset};
+pub use part::Part;
+pub use solution::Solution;
@@ -0,0 +1,92 @@
+//! Puzzle parameters that aren't part of the input.
+//!
+//! Some puzzles change a constant between the example in the text and the
+//! real input, e.g. how many pairs to connect or by how much empty space
+//! expands. Such a day declares its parameters as a type implementing
+//! [`Params`] with one value per [`Preset`], so bins, benches, tests and the
+//! runner all pick them by name instead of repeating magic numbers.
+
+use std::{fmt::Display, str::FromStr};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Preset {
+    /// The values of the example in the puzzle text.
+    Example,
+    /// The values of the real puzzle input.
+    #[default]
+    Real,
+}
+
+impl Preset {
+    pub const ALL: [Self; 2] = [Self::Example, Self::Real];
+
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Example => "example",
+            Self::Real => "real",
+        }
+    }
+}
+
+impl FromStr for Preset {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name() == s.trim())
+            .ok_or_else(|| format!("preset must be `example` or `real`, got `{s}`"))
+    }
+}
+
+impl Display for Preset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The parameters of a day, one set per [`Preset`].
+pub trait Params: Sized {
+    fn preset(preset: Preset) -> Self;
+
+    #[must_use]
+    fn example() -> Self {
+        Self::preset(Preset::Example)
+    }
+
+    #[must_use]
+    fn real() -> Self {
+        Self::preset(Preset::Real)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Size(usize);
+
+    impl Params for Size {
+        fn preset(preset: Preset) -> Self {
+            match preset {
+                Preset::Example => Self(10),
+                Preset::Real => Self(1000),
+            }
+        }
+    }
+
+    #[test]
+    fn test_presets() {
+        assert_eq!("example".parse(), Ok(Preset::Example));
+        assert_eq!(Preset::default().to_string(), "real");
+        assert!("huge".parse::<Preset>().is_err());
+        assert_eq!(Size::example(), Size(10));
+        assert_eq!(Size::preset("real".parse().unwrap()), Size(1000));
+    }
+}
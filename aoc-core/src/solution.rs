@@ -0,0 +1,101 @@
+//! Days split into a parse phase and two solve phases.
+
+use crate::Part;
+use miette::Result;
+use std::time::{Duration, Instant};
+
+/// A day whose input is parsed once into a [`Solution::Model`] that both
+/// parts are then solved from.
+///
+/// The `partN::process` functions of such a day are thin wrappers around
+/// [`Solution::parse`] and [`Solution::part1`]/[`Solution::part2`].
+pub trait Solution {
+    type Model;
+    type Answer: Into<crate::Answer>;
+
+    /// # Errors
+    ///
+    /// When the input is malformed.
+    fn parse(input: &str) -> Result<Self::Model>;
+
+    /// # Errors
+    ///
+    /// When the model has no answer.
+    fn part1(model: &Self::Model) -> Result<Self::Answer>;
+
+    /// # Errors
+    ///
+    /// When the model has no answer.
+    fn part2(model: &Self::Model) -> Result<Self::Answer>;
+
+    /// # Errors
+    ///
+    /// See [`Solution::part1`] and [`Solution::part2`].
+    fn solve(model: &Self::Model, part: Part) -> Result<Self::Answer> {
+        match part {
+            Part::One => Self::part1(model),
+            Part::Two => Self::part2(model),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Parses `input` and solves `part`, timing both phases.
+///
+/// # Errors
+///
+/// When either phase fails.
+pub fn timed<S: Solution>(part: Part, input: &str) -> Result<(S::Answer, Timings)> {
+    let start = Instant::now();
+    let model = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = S::solve(&model, part)?;
+    let solve = start.elapsed();
+
+    Ok((answer, Timings { parse, solve }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miette::miette;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Model = Vec<i64>;
+        type Answer = i64;
+
+        fn parse(input: &str) -> Result<Self::Model> {
+            input
+                .split_whitespace()
+                .map(|num| num.parse().map_err(|e| miette!("{e}")))
+                .collect()
+        }
+
+        fn part1(model: &Self::Model) -> Result<Self::Answer> {
+            Ok(model.iter().sum())
+        }
+
+        fn part2(model: &Self::Model) -> Result<Self::Answer> {
+            Ok(model.iter().product())
+        }
+    }
+
+    #[test]
+    fn test_timed() -> Result<()> {
+        let (answer, _) = timed::<Sum>(Part::One, "1 2 3 4")?;
+        assert_eq!(answer, 10);
+        let (answer, _) = timed::<Sum>(Part::Two, "1 2 3 4")?;
+        assert_eq!(answer, 24);
+        assert!(timed::<Sum>(Part::One, "1 x").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,264 @@
+//! Puzzle inputs and answers committed encrypted, so they can live in the
+//! repository without being published.
+//!
+//! Encrypted files sit under [`DIR`] at the repository root, named after the
+//! file they hold plus [`EXTENSION`], e.g. `inputs/2025/day-10.txt.enc`. Input
+//! resolution falls back to them, so the runner, benches and tests read them
+//! like any other input.
+//!
+//! Files are sealed with ChaCha20-Poly1305 under a key derived with Argon2id
+//! from the team passphrase, which is taken, in order, from:
+//!
+//! 1. the `AOC_PASSPHRASE` environment variable;
+//! 2. the file named by the `AOC_KEY_FILE` environment variable;
+//! 3. the first git-ignored `.aoc-key` file above the encrypted file.
+
+use argon2::Argon2;
+use chacha20poly1305::{
+    AeadCore, ChaCha20Poly1305, KeyInit, Nonce,
+    aead::{Aead, OsRng, rand_core::RngCore},
+};
+use miette::Diagnostic;
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+/// Directory of the encrypted files, relative to the repository root.
+pub const DIR: &str = "inputs";
+
+/// Extension appended to the name of an encrypted file.
+pub const EXTENSION: &str = "enc";
+
+/// Environment variable holding the passphrase.
+pub const PASSPHRASE_ENV: &str = "AOC_PASSPHRASE";
+
+/// Environment variable naming a file holding the passphrase.
+pub const KEY_FILE_ENV: &str = "AOC_KEY_FILE";
+
+/// File holding the passphrase, looked for above the encrypted file.
+pub const KEY_FILE: &str = ".aoc-key";
+
+/// Leads every encrypted file, so other files are told apart from ones
+/// sealed under another passphrase.
+const MAGIC: &[u8] = b"aoc-vault-1\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum VaultError {
+    #[error("no passphrase to decrypt {}", .path.display())]
+    #[diagnostic(
+        code(aoc::vault::no_key),
+        help(
+            "set `{PASSPHRASE_ENV}`, point `{KEY_FILE_ENV}` at the team key file or save it as `{KEY_FILE}` in the repository root"
+        )
+    )]
+    NoKey { path: PathBuf },
+    #[error("{} is not an encrypted file", .path.display())]
+    #[diagnostic(code(aoc::vault::format))]
+    Format { path: PathBuf },
+    #[error("failed to decrypt {}", .path.display())]
+    #[diagnostic(
+        code(aoc::vault::decrypt),
+        help("the passphrase is wrong or the file was tampered with")
+    )]
+    Decrypt { path: PathBuf },
+    #[error("failed to access {}", .path.display())]
+    #[diagnostic(code(aoc::vault::io))]
+    Io {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+}
+
+/// The team passphrase.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key(Vec<u8>);
+
+impl std::fmt::Debug for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Key(..)")
+    }
+}
+
+impl Key {
+    #[must_use]
+    pub fn new(passphrase: &str) -> Self {
+        Self(passphrase.as_bytes().to_vec())
+    }
+
+    /// Finds the passphrase for the encrypted file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// When there is none, or the key file can't be read.
+    pub fn find(path: &Path) -> Result<Self, VaultError> {
+        if let Some(passphrase) = env::var(PASSPHRASE_ENV).ok().filter(|var| !var.is_empty()) {
+            return Ok(Self::new(&passphrase));
+        }
+        let file = env::var_os(KEY_FILE_ENV)
+            .filter(|var| !var.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                path.ancestors()
+                    .skip(1)
+                    .map(|dir| dir.join(KEY_FILE))
+                    .find(|file| file.is_file())
+            })
+            .ok_or_else(|| VaultError::NoKey {
+                path: path.to_path_buf(),
+            })?;
+        let text =
+            fs::read_to_string(&file).map_err(|error| VaultError::Io { path: file, error })?;
+        Ok(Self::new(text.trim_end_matches(['\r', '\n'])))
+    }
+
+    fn cipher(&self, salt: &[u8]) -> ChaCha20Poly1305 {
+        let mut key = [0; 32];
+        Argon2::default()
+            .hash_password_into(&self.0, salt, &mut key)
+            .expect("the salt and key lengths are valid");
+        ChaCha20Poly1305::new(&key.into())
+    }
+
+    /// Encrypts `plain` under a fresh salt and nonce.
+    #[must_use]
+    pub fn seal(&self, plain: &[u8]) -> Vec<u8> {
+        let mut salt = [0; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        [MAGIC, &salt, &nonce, &self.encrypt(&salt, &nonce, plain)].concat()
+    }
+
+    fn encrypt(&self, salt: &[u8], nonce: &Nonce, plain: &[u8]) -> Vec<u8> {
+        self.cipher(salt)
+            .encrypt(nonce, plain)
+            .expect("the plaintext fits in memory")
+    }
+
+    /// Decrypts what [`seal`](Self::seal) encrypted, `None` when `sealed`
+    /// isn't an encrypted file or not one of this passphrase.
+    #[must_use]
+    pub fn open(&self, sealed: &[u8]) -> Option<Vec<u8>> {
+        let rest = sealed.strip_prefix(MAGIC)?;
+        if rest.len() < SALT_LEN + NONCE_LEN {
+            return None;
+        }
+        let (salt, rest) = rest.split_at(SALT_LEN);
+        let (nonce, sealed) = rest.split_at(NONCE_LEN);
+        self.cipher(salt)
+            .decrypt(Nonce::from_slice(nonce), sealed)
+            .ok()
+    }
+}
+
+/// Whether `path` names an encrypted file.
+#[must_use]
+pub fn is_encrypted(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == EXTENSION)
+}
+
+/// Path of the encrypted copy of the file named `name` of `year`, under the
+/// repository `root`.
+#[must_use]
+pub fn path(root: &Path, year: &str, name: &str) -> PathBuf {
+    root.join(DIR)
+        .join(year)
+        .join(format!("{name}.{EXTENSION}"))
+}
+
+/// Path of the encrypted input of the day whose crate is `day_dir`, e.g.
+/// `2025/day-10` reads `inputs/2025/day-10.txt.enc`.
+#[must_use]
+pub fn input_path(day_dir: &Path) -> Option<PathBuf> {
+    let day = day_dir.file_name()?.to_str()?;
+    let year_dir = day_dir.parent()?;
+    let year = year_dir.file_name()?.to_str()?;
+    Some(path(year_dir.parent()?, year, &format!("{day}.txt")))
+}
+
+/// Reads and decrypts the encrypted file at `path`.
+///
+/// # Errors
+///
+/// When it can't be read, there is no passphrase, or it doesn't decrypt to
+/// text.
+pub fn read(path: &Path) -> Result<String, VaultError> {
+    let sealed = fs::read(path).map_err(|error| VaultError::Io {
+        path: path.to_path_buf(),
+        error,
+    })?;
+    if !sealed.starts_with(MAGIC) {
+        return Err(VaultError::Format {
+            path: path.to_path_buf(),
+        });
+    }
+    let plain = Key::find(path)?
+        .open(&sealed)
+        .ok_or_else(|| VaultError::Decrypt {
+            path: path.to_path_buf(),
+        })?;
+    String::from_utf8(plain).map_err(|_| VaultError::Format {
+        path: path.to_path_buf(),
+    })
+}
+
+/// Encrypts `text` with `key` into `path`, replacing any earlier copy.
+///
+/// # Errors
+///
+/// When `path` can't be written.
+pub fn write(key: &Key, path: &Path, text: &str) -> Result<(), VaultError> {
+    let error = |error| VaultError::Io {
+        path: path.to_path_buf(),
+        error,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(error)?;
+    }
+    fs::write(path, key.seal(text.as_bytes())).map_err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal() {
+        let key = Key::new("correct horse battery staple");
+        let sealed = key.seal(b"1abc2\n");
+        assert!(sealed.starts_with(MAGIC));
+        assert_ne!(key.seal(b"1abc2\n"), sealed);
+        assert_eq!(key.open(&sealed).as_deref(), Some(&b"1abc2\n"[..]));
+
+        assert_eq!(Key::new("wrong").open(&sealed), None);
+        let mut tampered = sealed;
+        *tampered.last_mut().unwrap() ^= 1;
+        assert_eq!(key.open(&tampered), None);
+        assert_eq!(key.open(b"1abc2\n"), None);
+    }
+
+    #[test]
+    fn test_read() -> Result<(), VaultError> {
+        let root = env::temp_dir().join(format!("aoc-core-vault-{}", std::process::id()));
+        let file = input_path(&root.join("2025").join("day-10")).unwrap();
+        assert_eq!(
+            file,
+            root.join("inputs").join("2025").join("day-10.txt.enc")
+        );
+        assert!(is_encrypted(&file));
+
+        write(&Key::new("hunter2"), &file, "[.##.] (3) {3,5}\n")?;
+        assert!(matches!(read(&file), Err(VaultError::NoKey { .. })));
+        fs::write(root.join(KEY_FILE), "hunter2\n").unwrap();
+        assert_eq!(read(&file)?, "[.##.] (3) {3,5}\n");
+        fs::write(root.join(KEY_FILE), "hunter3\n").unwrap();
+        assert!(matches!(read(&file), Err(VaultError::Decrypt { .. })));
+
+        fs::remove_dir_all(root).unwrap();
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+//! The solutions as a Python module, for exploring inputs in notebooks:
+//!
+//! ```python
+//! import aoc
+//!
+//! aoc.solve(2025, 8, 1, open("2025/day-08/input1.txt").read())
+//! aoc.solve(2025, 8, 1, example, preset="example")
+//! aoc.coordinates(text)  # 2025 day 08: [(162.0, 817.0, 812.0), ...]
+//! aoc.rack(text)  # 2025 day 11: {"you": ["bbb", "ccc"], ...}
+//! ```
+//!
+//! Build and install it into the active virtualenv with
+//! `maturin develop -m aoc-py/pyproject.toml`.
+
+use aoc::{
+    registry::{self, Answer, Part, Preset, Solved},
+    run,
+};
+use pyo3::{
+    exceptions::{PyNotImplementedError, PyRuntimeError, PyValueError},
+    prelude::*,
+};
+use std::collections::BTreeMap;
+use y2025_day_08::Coordinates;
+use y2025_day_11::Rack;
+
+/// Integers become Python integers, whatever their size, everything else a
+/// string.
+fn to_python(py: Python<'_>, answer: Answer) -> PyResult<PyObject> {
+    Ok(match answer {
+        Answer::Int(int) => int.into_pyobject(py)?.into_any().unbind(),
+        Answer::UInt(uint) => uint.into_pyobject(py)?.into_any().unbind(),
+        Answer::Text(text) | Answer::Grid(text) => text.into_pyobject(py)?.into_any().unbind(),
+    })
+}
+
+/// Solves `part` of `day` of `year` on `input`.
+///
+/// Raises `ValueError` for parts that aren't registered,
+/// `NotImplementedError` for parts that are still `todo!()` and
+/// `RuntimeError` for parts that fail.
+#[pyfunction]
+#[pyo3(signature = (year, day, part, input, preset = "real"))]
+fn solve(
+    py: Python<'_>,
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    preset: &str,
+) -> PyResult<PyObject> {
+    let part = part
+        .to_string()
+        .parse::<Part>()
+        .map_err(PyValueError::new_err)?;
+    let preset = preset.parse::<Preset>().map_err(PyValueError::new_err)?;
+    let solver = registry::find(year, day)
+        .and_then(|day| day.solver(part))
+        .ok_or_else(|| {
+            PyValueError::new_err(format!("no solution for {year} day {day:02} part {part}"))
+        })?;
+
+    let outcome = py.allow_threads(|| run::run(solver, input, preset));
+    match outcome.answer {
+        Ok(Solved { answer, .. }) => to_python(py, answer),
+        Err(failure) if failure.is_unimplemented() => {
+            Err(PyNotImplementedError::new_err(failure.to_string()))
+        }
+        Err(failure) => Err(PyRuntimeError::new_err(failure.to_string())),
+    }
+}
+
+/// The junction boxes of 2025 day 08 as `(x, y, z)` tuples.
+#[pyfunction]
+fn coordinates(input: &str) -> PyResult<Vec<(f32, f32, f32)>> {
+    let coordinates = input
+        .parse::<Coordinates>()
+        .map_err(PyValueError::new_err)?;
+    Ok(coordinates
+        .positions()
+        .iter()
+        .map(|position| (position.x, position.y, position.z))
+        .collect())
+}
+
+/// The devices of 2025 day 11, each mapped to the devices it outputs to.
+#[pyfunction]
+fn rack(input: &str) -> PyResult<BTreeMap<String, Vec<String>>> {
+    let rack = input.parse::<Rack>().map_err(PyValueError::new_err)?;
+    Ok(rack
+        .devices()
+        .iter()
+        .map(|device| {
+            let outputs = device.outputs().iter().map(ToString::to_string).collect();
+            (device.input().to_string(), outputs)
+        })
+        .collect())
+}
+
+#[pymodule]
+#[pyo3(name = "aoc")]
+fn bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(coordinates, m)?)?;
+    m.add_function(wrap_pyfunction!(rack, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    #[test]
+    fn test_module() -> PyResult<()> {
+        pyo3::append_to_inittab!(bindings);
+        Python::with_gil(|py| {
+            let globals = PyDict::new(py);
+            py.run(
+                cr##"
+import aoc
+
+assert aoc.solve(2023, 1, 1, "1abc2\ntreb7uchet\n") == 89
+assert aoc.solve(2025, 8, 1, """162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689""", preset="example") == 40
+
+try:
+    aoc.solve(2023, 11, 2, "#.\n.#\n")
+except NotImplementedError:
+    pass
+else:
+    raise AssertionError("part 2 of 2023 day 11 is a stub")
+
+assert aoc.coordinates("1,2,3\n4,5,6\n") == [(1.0, 2.0, 3.0), (4.0, 5.0, 6.0)]
+assert aoc.rack("you: bbb out\nbbb: out\n") == {"you": ["bbb", "out"], "bbb": ["out"]}
+"##,
+                Some(&globals),
+                None,
+            )
+        })
+    }
+}
@@ -0,0 +1,151 @@
+//! Hashes the sources of every day crate, so cached answers can be thrown
+//! away once the code that computed them changes.
+//!
+//! The hash of a day covers its own crate, its path dependencies such as
+//! `aoc-core`, and the lock file of its year, which pins every other one.
+
+use sha2::{Digest, Sha256};
+use std::{
+    env,
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+};
+use toml::Table;
+
+fn main() -> io::Result<()> {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .parent()
+        .unwrap()
+        .to_path_buf();
+
+    let mut table = String::from("pub const SOURCES: &[(u16, u8, &str)] = &[\n");
+    for year in ["2023", "2024", "2025"] {
+        let year_dir = root.join(year);
+        let manifest = read_manifest(&year_dir)?;
+        let workspace = path_dependencies(
+            &year_dir,
+            [manifest
+                .get("workspace")
+                .and_then(|workspace| workspace.get("dependencies"))],
+        );
+        let lock = year_dir.join("Cargo.lock");
+        if lock.is_file() {
+            println!("cargo:rerun-if-changed={}", lock.display());
+        }
+
+        let mut days = fs::read_dir(&year_dir)?
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                let day = name.strip_prefix("day-")?.parse::<u8>().ok()?;
+                Some((day, entry.path()))
+            })
+            .collect::<Vec<_>>();
+        days.sort();
+
+        for (day, dir) in days {
+            let manifest = read_manifest(&dir)?;
+            let mut crates = vec![dir.clone()];
+            // dev-dependencies don't change the answers
+            let dependencies = [
+                manifest.get("dependencies"),
+                manifest.get("build-dependencies"),
+            ];
+            for (name, path) in path_dependencies(&dir, dependencies) {
+                if let Some(path) = path.or_else(|| {
+                    workspace
+                        .iter()
+                        .find(|(dependency, _)| *dependency == name)
+                        .and_then(|(_, path)| path.clone())
+                }) {
+                    crates.push(path);
+                }
+            }
+
+            let mut hasher = Sha256::new();
+            for (i, dir) in crates.iter().enumerate() {
+                println!("cargo:rerun-if-changed={}", dir.join("src").display());
+                println!(
+                    "cargo:rerun-if-changed={}",
+                    dir.join("Cargo.toml").display()
+                );
+                hasher.update(i.to_le_bytes());
+                hash_crate(dir, &mut hasher)?;
+            }
+            if lock.is_file() {
+                hasher.update(fs::read(&lock)?);
+            }
+            let hash = hasher
+                .finalize()
+                .iter()
+                .fold(String::new(), |mut hex, byte| {
+                    let _ = write!(hex, "{byte:02x}");
+                    hex
+                });
+            let _ = writeln!(table, "    ({year}, {day}, \"{hash}\"),");
+        }
+    }
+    table.push_str("];\n");
+
+    fs::write(
+        PathBuf::from(env::var("OUT_DIR").unwrap()).join("sources.rs"),
+        table,
+    )
+}
+
+fn read_manifest(dir: &Path) -> io::Result<Table> {
+    fs::read_to_string(dir.join("Cargo.toml"))?
+        .parse()
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// The dependencies listed in `tables` of the manifest of the crate at
+/// `dir`, by name, with the directory of those given by path.
+fn path_dependencies<'a>(
+    dir: &Path,
+    tables: impl IntoIterator<Item = Option<&'a toml::Value>>,
+) -> Vec<(String, Option<PathBuf>)> {
+    tables
+        .into_iter()
+        .flatten()
+        .filter_map(toml::Value::as_table)
+        .flatten()
+        .map(|(name, dependency)| {
+            let path = dependency
+                .get("path")
+                .and_then(toml::Value::as_str)
+                .map(|path| dir.join(path));
+            (name.clone(), path)
+        })
+        .collect()
+}
+
+/// Feeds the manifest and everything below `src/` of the crate at `dir`
+/// into `hasher`.
+fn hash_crate(dir: &Path, hasher: &mut Sha256) -> io::Result<()> {
+    let mut files = vec![dir.join("Cargo.toml")];
+    collect(&dir.join("src"), &mut files)?;
+    files.sort();
+
+    for file in files {
+        let relative = file.strip_prefix(dir).unwrap_or(&file);
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(fs::read(&file)?);
+        hasher.update([0]);
+    }
+    Ok(())
+}
+
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
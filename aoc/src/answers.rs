@@ -0,0 +1,245 @@
+//! The accepted answers for our inputs, one `answers.toml` per year:
+//!
+//! ```toml
+//! [day-01]
+//! part1 = 55712
+//! part2 = "55413"
+//! ```
+//!
+//! Answers may be written as integers or strings, both are read as an
+//! [`Answer`], so `55413` and `"55413"` are the same answer. Grid answers are
+//! written as multi-line strings.
+//!
+//! Only the encrypted copy in the [`vault`](crate::vault) is committed. Parts
+//! the plaintext `answers.toml` doesn't record are read from it, when there is
+//! one and a passphrase to open it.
+
+use crate::{
+    archive::ArchiveError,
+    registry::{self, Answer, Part},
+    vault::{self, VaultError},
+};
+use miette::Diagnostic;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+use thiserror::Error;
+
+pub const FILE: &str = "answers.toml";
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum AnswersError {
+    #[error("failed to read {}", .path.display())]
+    #[diagnostic(code(aoc::answers::read))]
+    Read {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+    #[error("failed to parse {}", .path.display())]
+    #[diagnostic(code(aoc::answers::parse))]
+    Parse {
+        path: PathBuf,
+        #[source]
+        error: toml::de::Error,
+    },
+    #[error("`{key}` in {} is not a day", .path.display())]
+    #[diagnostic(code(aoc::answers::day), help("days are written as `[day-01]`"))]
+    Day { path: PathBuf, key: String },
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Vault(#[from] VaultError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Archive(#[from] ArchiveError),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Recorded {
+    Number(i64),
+    Text(String),
+}
+
+impl From<Recorded> for Answer {
+    fn from(value: Recorded) -> Self {
+        match value {
+            Recorded::Number(num) => num.into(),
+            Recorded::Text(text) => text.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parts {
+    part1: Option<Recorded>,
+    part2: Option<Recorded>,
+}
+
+/// The recorded answers of one year.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u8, Part), Answer>);
+
+impl Answers {
+    /// Location of the answers file of `year`, e.g. `2023/answers.toml`.
+    #[must_use]
+    pub fn path(year: u16) -> PathBuf {
+        registry::root().join(year.to_string()).join(FILE)
+    }
+
+    /// Location of the encrypted answers of `year`, e.g.
+    /// `inputs/2023/answers.toml.enc`.
+    #[must_use]
+    pub fn vault_path(year: u16) -> PathBuf {
+        vault::path(&registry::root(), &year.to_string(), FILE)
+    }
+
+    /// Loads the answers of `year`, those of the plaintext file first and
+    /// the rest from their encrypted copy; a missing file, or an encrypted one
+    /// without a passphrase, means nothing is recorded there yet.
+    ///
+    /// # Errors
+    ///
+    /// When a file exists but can't be read, decrypted or parsed.
+    pub fn load(year: u16) -> Result<Self, AnswersError> {
+        Self::load_from(&Self::path(year), &Self::vault_path(year))
+    }
+
+    fn load_from(path: &Path, sealed: &Path) -> Result<Self, AnswersError> {
+        let mut answers = match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text, path)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Self::default(),
+            Err(error) => {
+                return Err(AnswersError::Read {
+                    path: path.to_path_buf(),
+                    error,
+                });
+            }
+        };
+        if sealed.is_file() {
+            match aoc_core::vault::read(sealed) {
+                Ok(text) => {
+                    let sealed = Self::parse(&text, sealed)?;
+                    answers.fill(
+                        sealed
+                            .0
+                            .into_iter()
+                            .map(|((day, part), answer)| (day, part, answer)),
+                    );
+                }
+                Err(VaultError::NoKey { .. }) => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+        Ok(answers)
+    }
+
+    /// # Errors
+    ///
+    /// When `text` isn't a valid answers file. `path` is only used in errors.
+    pub fn parse(text: &str, path: &Path) -> Result<Self, AnswersError> {
+        let days = toml::from_str::<BTreeMap<String, Parts>>(text).map_err(|error| {
+            AnswersError::Parse {
+                path: path.to_path_buf(),
+                error,
+            }
+        })?;
+
+        let mut answers = BTreeMap::new();
+        for (key, parts) in days {
+            let day = key
+                .strip_prefix("day-")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| AnswersError::Day {
+                    path: path.to_path_buf(),
+                    key: key.clone(),
+                })?;
+            for (part, answer) in [(Part::One, parts.part1), (Part::Two, parts.part2)] {
+                if let Some(answer) = answer {
+                    answers.insert((day, part), answer.into());
+                }
+            }
+        }
+        Ok(Self(answers))
+    }
+
+    /// Adds the `accepted` answers of parts nothing is recorded for yet.
+    pub fn fill(&mut self, accepted: impl IntoIterator<Item = (u8, Part, Answer)>) {
+        for (day, part, answer) in accepted {
+            self.0.entry((day, part)).or_insert(answer);
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<(), AnswersError> {
+        let answers = Answers::parse(
+            "[day-01]\npart1 = 55712\npart2 = \"55413\"\n\n[day-11]\npart1 = 9947476\n",
+            Path::new(FILE),
+        )?;
+        assert_eq!(answers.get(1, Part::One), Some(&Answer::Int(55712)));
+        assert_eq!(answers.get(1, Part::Two), Some(&Answer::Int(55413)));
+        assert_eq!(answers.get(11, Part::Two), None);
+        assert_eq!(answers.len(), 3);
+
+        let err = Answers::parse("[first]\npart1 = 1\n", Path::new(FILE)).unwrap_err();
+        assert!(matches!(err, AnswersError::Day { key, .. } if key == "first"));
+        assert!(Answers::parse("[day-01]\npart3 = 1\n", Path::new(FILE)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_fills_in_from_the_vault() -> Result<(), AnswersError> {
+        let root = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join(aoc_core::vault::KEY_FILE), "hunter2\n").unwrap();
+        let (path, sealed) = (root.join(FILE), root.join("answers.toml.enc"));
+        fs::write(&path, "[day-01]\npart1 = 3\n").unwrap();
+        let key = vault::Key::find(&sealed)?;
+        aoc_core::vault::write(&key, &sealed, "[day-01]\npart1 = 4\npart2 = 6\n")?;
+
+        let answers = Answers::load_from(&path, &sealed)?;
+        assert_eq!(answers.get(1, Part::One), Some(&Answer::Int(3)));
+        assert_eq!(answers.get(1, Part::Two), Some(&Answer::Int(6)));
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            Answers::load_from(&path, &sealed)?.get(1, Part::One),
+            Some(&Answer::Int(4))
+        );
+
+        fs::remove_dir_all(root).unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_committed_answers_parse() -> Result<(), AnswersError> {
+        for year in registry::years() {
+            Answers::load(year)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,483 @@
+//! A local `SQLite` archive of everything known about each puzzle: its
+//! description, our input, its examples, every guess submitted and the
+//! accepted answers.
+//!
+//! `aoc fetch` and `aoc submit` write to it as they go, `aoc submit` checks
+//! every answer against the guesses before it, and `aoc verify` both reads
+//! accepted answers missing from `answers.toml` and records the ones it
+//! confirms. It lives in the git-ignored `.aoc/archive.sqlite` and exports to
+//! JSON with `aoc export`.
+
+use crate::{
+    registry::{self, Answer, Part},
+    submit::{Guess, Verdict},
+};
+use miette::Diagnostic;
+use rusqlite::{Connection, OptionalExtension, params, types::ValueRef};
+use serde_json::{Map, Value};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS puzzles (
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    description TEXT NOT NULL,
+    fetched_at INTEGER NOT NULL,
+    PRIMARY KEY (year, day)
+);
+CREATE TABLE IF NOT EXISTS inputs (
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    input TEXT NOT NULL,
+    fetched_at INTEGER NOT NULL,
+    PRIMARY KEY (year, day)
+);
+CREATE TABLE IF NOT EXISTS examples (
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    input TEXT NOT NULL,
+    answer TEXT,
+    PRIMARY KEY (year, day, part, name)
+);
+CREATE TABLE IF NOT EXISTS guesses (
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    answer TEXT NOT NULL,
+    verdict TEXT NOT NULL,
+    -- seconds to wait, of a rate-limited guess that was told
+    wait INTEGER,
+    at INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS answers (
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    answer TEXT NOT NULL,
+    accepted_at INTEGER NOT NULL,
+    PRIMARY KEY (year, day, part)
+);
+";
+
+/// Every table, in the order they are exported.
+const TABLES: [&str; 5] = ["puzzles", "inputs", "examples", "guesses", "answers"];
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum ArchiveError {
+    #[error("failed to create {}", .path.display())]
+    #[diagnostic(code(aoc::archive::create))]
+    Create {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+    #[error("failed to query the archive")]
+    #[diagnostic(
+        code(aoc::archive::query),
+        help("the archive is a cache of what was fetched, delete it to start over")
+    )]
+    Query(#[from] rusqlite::Error),
+    #[error("the archive holds a verdict it doesn't know, {verdict:?}")]
+    #[diagnostic(code(aoc::archive::verdict))]
+    Verdict { verdict: String },
+}
+
+pub struct Archive {
+    connection: Connection,
+}
+
+/// An example of a part as archived, with the answer the puzzle gives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answer: Answer,
+}
+
+impl std::fmt::Debug for Archive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Archive")
+            .field("path", &self.connection.path())
+            .finish()
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| {
+            i64::try_from(since.as_secs()).unwrap_or(i64::MAX)
+        })
+}
+
+impl Archive {
+    #[must_use]
+    pub fn path() -> PathBuf {
+        registry::root().join(".aoc").join("archive.sqlite")
+    }
+
+    /// Opens the archive at [`path`](Self::path), creating it when missing.
+    ///
+    /// # Errors
+    ///
+    /// When it can't be created or opened.
+    pub fn open() -> Result<Self, ArchiveError> {
+        Self::open_at(&Self::path())
+    }
+
+    /// # Errors
+    ///
+    /// When the archive at `path` can't be created or opened.
+    pub fn open_at(path: &Path) -> Result<Self, ArchiveError> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|error| ArchiveError::Create {
+                path: dir.to_path_buf(),
+                error,
+            })?;
+        }
+        Self::init(Connection::open(path)?)
+    }
+
+    /// An archive kept in memory only, forgotten once dropped.
+    ///
+    /// # Errors
+    ///
+    /// When `SQLite` can't allocate it.
+    pub fn memory() -> Result<Self, ArchiveError> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(connection: Connection) -> Result<Self, ArchiveError> {
+        connection.execute_batch(SCHEMA)?;
+        Ok(Self { connection })
+    }
+
+    /// # Errors
+    ///
+    /// When the archive can't be written.
+    pub fn save_puzzle(&self, year: u16, day: u8, description: &str) -> Result<(), ArchiveError> {
+        self.connection.execute(
+            "INSERT OR REPLACE INTO puzzles VALUES (?1, ?2, ?3, ?4)",
+            params![year, day, description, now()],
+        )?;
+        Ok(())
+    }
+
+    /// The description of `day` of `year` as Markdown, as last fetched.
+    ///
+    /// # Errors
+    ///
+    /// When the archive can't be read.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<Option<String>, ArchiveError> {
+        Ok(self
+            .connection
+            .query_row(
+                "SELECT description FROM puzzles WHERE year = ?1 AND day = ?2",
+                params![year, day],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// # Errors
+    ///
+    /// When the archive can't be written.
+    pub fn save_input(&self, year: u16, day: u8, input: &str) -> Result<(), ArchiveError> {
+        self.connection.execute(
+            "INSERT OR REPLACE INTO inputs VALUES (?1, ?2, ?3, ?4)",
+            params![year, day, input, now()],
+        )?;
+        Ok(())
+    }
+
+    /// # Errors
+    ///
+    /// When the archive can't be read.
+    pub fn input(&self, year: u16, day: u8) -> Result<Option<String>, ArchiveError> {
+        Ok(self
+            .connection
+            .query_row(
+                "SELECT input FROM inputs WHERE year = ?1 AND day = ?2",
+                params![year, day],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Saves the example `name` of `part`, with the answer it expects when
+    /// the description gives one.
+    ///
+    /// # Errors
+    ///
+    /// When the archive can't be written.
+    pub fn save_example(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        name: &str,
+        input: &str,
+        answer: Option<&Answer>,
+    ) -> Result<(), ArchiveError> {
+        self.connection.execute(
+            "INSERT OR REPLACE INTO examples VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                year,
+                day,
+                part.number(),
+                name,
+                input,
+                answer.map(ToString::to_string)
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// The examples of `part` with an answer, sorted by name.
+    ///
+    /// # Errors
+    ///
+    /// When the archive can't be read.
+    pub fn examples(&self, year: u16, day: u8, part: Part) -> Result<Vec<Example>, ArchiveError> {
+        let mut statement = self.connection.prepare(
+            "SELECT name, input, answer FROM examples
+             WHERE year = ?1 AND day = ?2 AND part = ?3 AND answer IS NOT NULL
+             ORDER BY name",
+        )?;
+        let examples = statement
+            .query_map(params![year, day, part.number()], |row| {
+                Ok(Example {
+                    name: row.get(0)?,
+                    input: row.get(1)?,
+                    answer: Answer::from(row.get::<_, String>(2)?),
+                })
+            })?
+            .collect::<Result<_, _>>()?;
+        Ok(examples)
+    }
+
+    /// Records a submitted guess, and its answer as accepted when it was
+    /// judged correct.
+    ///
+    /// # Errors
+    ///
+    /// When the archive can't be written.
+    pub fn record(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        guess: &Guess,
+    ) -> Result<(), ArchiveError> {
+        self.connection.execute(
+            "INSERT INTO guesses VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![
+                year,
+                day,
+                part.number(),
+                guess.answer.to_string(),
+                guess.verdict.name(),
+                match guess.verdict {
+                    Verdict::RateLimited { wait } => wait.map(|wait| wait.as_secs()),
+                    _ => None,
+                },
+                guess.at
+            ],
+        )?;
+        if guess.verdict == Verdict::Correct {
+            self.accept(year, day, part, &guess.answer)?;
+        }
+        Ok(())
+    }
+
+    /// The guesses of a part, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// When the archive can't be read.
+    pub fn guesses(&self, year: u16, day: u8, part: Part) -> Result<Vec<Guess>, ArchiveError> {
+        let mut statement = self.connection.prepare(
+            "SELECT answer, verdict, wait, at FROM guesses
+             WHERE year = ?1 AND day = ?2 AND part = ?3
+             ORDER BY at, rowid",
+        )?;
+        let rows = statement
+            .query_map(params![year, day, part.number()], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<u64>>(2)?,
+                    row.get(3)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        rows.into_iter()
+            .map(|(answer, verdict, wait, at)| {
+                Ok(Guess {
+                    answer: Answer::from(answer),
+                    verdict: Verdict::from_name(&verdict, wait.map(Duration::from_secs))
+                        .ok_or(ArchiveError::Verdict { verdict })?,
+                    at,
+                })
+            })
+            .collect()
+    }
+
+    /// # Errors
+    ///
+    /// When the archive can't be written.
+    pub fn accept(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<(), ArchiveError> {
+        self.connection.execute(
+            "INSERT INTO answers VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT DO UPDATE SET answer = excluded.answer, accepted_at = excluded.accepted_at
+             WHERE answer != excluded.answer",
+            params![year, day, part.number(), answer.to_string(), now()],
+        )?;
+        Ok(())
+    }
+
+    /// The accepted answers of `year` by day and part.
+    ///
+    /// # Errors
+    ///
+    /// When the archive can't be read.
+    pub fn accepted(&self, year: u16) -> Result<Vec<(u8, Part, Answer)>, ArchiveError> {
+        let mut statement = self
+            .connection
+            .prepare("SELECT day, part, answer FROM answers WHERE year = ?1 ORDER BY day, part")?;
+        let accepted = statement
+            .query_map(params![year], |row| {
+                let part = if row.get::<_, u8>(1)? == 1 {
+                    Part::One
+                } else {
+                    Part::Two
+                };
+                Ok((row.get(0)?, part, Answer::from(row.get::<_, String>(2)?)))
+            })?
+            .collect::<Result<_, _>>()?;
+        Ok(accepted)
+    }
+
+    /// Every table as an array of rows, each row an object keyed by column.
+    ///
+    /// # Errors
+    ///
+    /// When the archive can't be read.
+    pub fn export(&self) -> Result<Value, ArchiveError> {
+        let mut tables = Map::new();
+        for table in TABLES {
+            let mut statement = self
+                .connection
+                .prepare(&format!("SELECT * FROM {table} ORDER BY rowid"))?;
+            let columns = statement
+                .column_names()
+                .into_iter()
+                .map(String::from)
+                .collect::<Vec<_>>();
+            let rows = statement
+                .query_map([], |row| {
+                    columns
+                        .iter()
+                        .enumerate()
+                        .map(|(i, column)| Ok((column.clone(), json(row.get_ref(i)?))))
+                        .collect::<Result<Map<_, _>, _>>()
+                        .map(Value::Object)
+                })?
+                .collect::<Result<_, _>>()?;
+            tables.insert(table.to_string(), Value::Array(rows));
+        }
+        Ok(Value::Object(tables))
+    }
+}
+
+fn json(value: ValueRef) -> Value {
+    match value {
+        ValueRef::Null | ValueRef::Blob(_) => Value::Null,
+        ValueRef::Integer(int) => int.into(),
+        ValueRef::Real(real) => real.into(),
+        ValueRef::Text(text) => String::from_utf8_lossy(text).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive() -> Result<(), ArchiveError> {
+        let archive = Archive::memory()?;
+        assert_eq!(archive.input(2025, 1)?, None);
+        archive.save_input(2025, 1, "L68\nR48\n")?;
+        archive.save_puzzle(2025, 1, "## --- Day 1 ---\n")?;
+        archive.save_puzzle(2025, 1, "## --- Day 1 ---\n\n## --- Part Two ---\n")?;
+        assert_eq!(archive.input(2025, 1)?.as_deref(), Some("L68\nR48\n"));
+        assert!(archive.puzzle(2025, 1)?.unwrap().contains("Part Two"));
+
+        archive.save_example(
+            2025,
+            1,
+            Part::One,
+            "example",
+            "L68\n",
+            Some(&Answer::Int(3)),
+        )?;
+        archive.save_example(2025, 1, Part::Two, "example", "L68\n", None)?;
+        assert_eq!(
+            archive.examples(2025, 1, Part::One)?[0].answer,
+            Answer::Int(3)
+        );
+        assert!(archive.examples(2025, 1, Part::Two)?.is_empty());
+
+        let guess = |answer: i64, verdict| Guess {
+            answer: Answer::from(answer),
+            verdict,
+            at: 1_764_565_452,
+        };
+        let limited = Verdict::RateLimited {
+            wait: Some(Duration::from_secs(64)),
+        };
+        archive.record(2025, 1, Part::One, &guess(100, Verdict::TooHigh))?;
+        archive.record(2025, 1, Part::One, &guess(4, limited.clone()))?;
+        archive.record(2025, 1, Part::One, &guess(3, Verdict::Correct))?;
+        assert_eq!(
+            archive.guesses(2025, 1, Part::One)?,
+            [
+                guess(100, Verdict::TooHigh),
+                guess(4, limited),
+                guess(3, Verdict::Correct)
+            ]
+        );
+        archive.accept(2025, 1, Part::Two, &Answer::Int(6))?;
+        assert_eq!(
+            archive.accepted(2025)?,
+            [
+                (1, Part::One, Answer::Int(3)),
+                (1, Part::Two, Answer::Int(6))
+            ]
+        );
+        assert!(archive.accepted(2024)?.is_empty());
+
+        let export = archive.export()?;
+        assert_eq!(export["inputs"][0]["input"], "L68\nR48\n");
+        assert_eq!(export["examples"][1]["answer"], Value::Null);
+        assert_eq!(export["guesses"][0]["verdict"], "too_high");
+        assert_eq!(export["guesses"][0]["wait"], Value::Null);
+        assert_eq!(export["guesses"][1]["verdict"], "rate_limited");
+        assert_eq!(export["guesses"][1]["wait"], 64);
+        assert_eq!(export["answers"].as_array().map(Vec::len), Some(2));
+        Ok(())
+    }
+}
@@ -0,0 +1,319 @@
+//! Solving one day against a whole directory of inputs, e.g. those of several
+//! accounts cross-checking each other's answers.
+
+use crate::{
+    registry::{Answer, Day, Part, Solved},
+    report::{Format, csv_field, table, xml_escape},
+    run::{Failure, Runner},
+};
+use miette::Diagnostic;
+use rayon::prelude::*;
+use serde::Serialize;
+use std::{
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum BatchError {
+    #[error("failed to list inputs in {}", .path.display())]
+    #[diagnostic(code(aoc::batch::read))]
+    Read {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+    #[error("no inputs in {}", .path.display())]
+    #[diagnostic(
+        code(aoc::batch::empty),
+        help("inputs are the `*.txt` files of the directory")
+    )]
+    Empty { path: PathBuf },
+}
+
+/// The `*.txt` files directly inside `dir`, sorted by name.
+///
+/// # Errors
+///
+/// When `dir` can't be listed or holds no inputs.
+pub fn inputs(dir: &Path) -> Result<Vec<PathBuf>, BatchError> {
+    let read = |error| BatchError::Read {
+        path: dir.to_path_buf(),
+        error,
+    };
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).map_err(read)? {
+        let path = entry.map_err(read)?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "txt") {
+            files.push(path);
+        }
+    }
+    if files.is_empty() {
+        return Err(BatchError::Empty {
+            path: dir.to_path_buf(),
+        });
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// One part solved on one input file.
+#[derive(Debug)]
+pub struct Entry {
+    pub file: PathBuf,
+    pub part: Part,
+    /// `None` when the part isn't implemented.
+    pub answer: Option<Result<Solved, Failure>>,
+    pub elapsed: Option<Duration>,
+}
+
+impl Entry {
+    #[must_use]
+    pub fn label(&self) -> &'static str {
+        match &self.answer {
+            Some(Ok(_)) => "solved",
+            Some(Err(failure)) if !failure.is_unimplemented() => "fail",
+            _ => "unimplemented",
+        }
+    }
+
+    fn name(&self) -> String {
+        self.file.file_name().map_or_else(
+            || self.file.display().to_string(),
+            |name| name.to_string_lossy().into_owned(),
+        )
+    }
+
+    const fn answer(&self) -> Option<&Answer> {
+        match &self.answer {
+            Some(Ok(solved)) => Some(&solved.answer),
+            _ => None,
+        }
+    }
+
+    fn detail(&self) -> Option<String> {
+        match &self.answer {
+            Some(Err(failure)) if !failure.is_unimplemented() => Some(failure.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Solves `parts` of `day` on every one of `files` in parallel, keeping their
+/// order.
+#[must_use]
+pub fn solve(day: &Day, parts: &[Part], files: &[PathBuf], runner: Runner) -> Vec<Entry> {
+    files
+        .iter()
+        .flat_map(|file| parts.iter().map(move |&part| (file, part)))
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|(file, part)| {
+            let (answer, elapsed) = match fs::read_to_string(file) {
+                Ok(input) => runner
+                    .solve(day, part, &input)
+                    .map_or((None, None), |outcome| {
+                        (Some(outcome.answer), Some(outcome.elapsed))
+                    }),
+                Err(err) => (Some(Err(Failure::Error(miette::miette!("{err}")))), None),
+            };
+            Entry {
+                file: file.clone(),
+                part,
+                answer,
+                elapsed,
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct Batch {
+    pub entries: Vec<Entry>,
+    /// Wall-clock time of the whole batch.
+    pub total: Duration,
+}
+
+#[derive(Debug, Serialize)]
+struct Row {
+    input: String,
+    part: u8,
+    status: &'static str,
+    answer: Option<String>,
+    detail: Option<String>,
+    seconds: Option<f64>,
+}
+
+impl From<&Entry> for Row {
+    fn from(entry: &Entry) -> Self {
+        Self {
+            input: entry.name(),
+            part: entry.part.number(),
+            status: entry.label(),
+            answer: entry.answer().map(ToString::to_string),
+            detail: entry.detail(),
+            seconds: entry.elapsed.map(|elapsed| elapsed.as_secs_f64()),
+        }
+    }
+}
+
+impl Batch {
+    #[must_use]
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Pretty => self.pretty(),
+            Format::Json => self.json(),
+            Format::Csv => self.csv(),
+            Format::Junit => self.junit(),
+        }
+    }
+
+    fn pretty(&self) -> String {
+        let rows = self
+            .entries
+            .iter()
+            .map(|entry| {
+                [
+                    entry.name(),
+                    entry.part.to_string(),
+                    entry.label().to_string(),
+                    entry.answer().map(ToString::to_string).unwrap_or_default(),
+                    entry
+                        .elapsed
+                        .map(|elapsed| format!("{elapsed:.2?}"))
+                        .unwrap_or_default(),
+                    entry.detail().unwrap_or_default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let mut out = table(&["input", "part", "status", "answer", "time", ""], &rows);
+        let count = |label| {
+            self.entries
+                .iter()
+                .filter(|entry| entry.label() == label)
+                .count()
+        };
+        let _ = write!(
+            out,
+            "{} solved, {} failed, {} unimplemented in {:.2?}",
+            count("solved"),
+            count("fail"),
+            count("unimplemented"),
+            self.total,
+        );
+        out
+    }
+
+    fn json(&self) -> String {
+        #[derive(Serialize)]
+        struct Json {
+            seconds: f64,
+            inputs: Vec<Row>,
+        }
+        let json = Json {
+            seconds: self.total.as_secs_f64(),
+            inputs: self.entries.iter().map(Row::from).collect(),
+        };
+        serde_json::to_string_pretty(&json).expect("rows serialize to JSON")
+    }
+
+    fn csv(&self) -> String {
+        let mut out = "input,part,status,answer,seconds,detail\n".to_string();
+        for row in self.entries.iter().map(Row::from) {
+            let fields = [
+                row.input,
+                row.part.to_string(),
+                row.status.to_string(),
+                row.answer.unwrap_or_default(),
+                row.seconds.map(|s| s.to_string()).unwrap_or_default(),
+                row.detail.unwrap_or_default(),
+            ];
+            let fields = fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>();
+            out.push_str(&fields.join(","));
+            out.push('\n');
+        }
+        out
+    }
+
+    fn junit(&self) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            out,
+            "<testsuite name=\"batch\" tests=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+            self.entries.len(),
+            self.entries.iter().filter(|e| e.label() == "fail").count(),
+            self.entries
+                .iter()
+                .filter(|e| e.label() == "unimplemented")
+                .count(),
+            self.total.as_secs_f64(),
+        );
+        for entry in &self.entries {
+            let _ = write!(
+                out,
+                "  <testcase classname=\"{}\" name=\"part {}\" time=\"{:.3}\"",
+                xml_escape(&entry.name()),
+                entry.part,
+                entry.elapsed.unwrap_or_default().as_secs_f64(),
+            );
+            match entry.label() {
+                "solved" => out.push_str("/>\n"),
+                "fail" => {
+                    let _ = writeln!(
+                        out,
+                        ">\n    <error message=\"{}\"/>\n  </testcase>",
+                        xml_escape(&entry.detail().unwrap_or_default())
+                    );
+                }
+                _ => out.push_str(">\n    <skipped message=\"unimplemented\"/>\n  </testcase>\n"),
+            }
+        }
+        out.push_str("</testsuite>");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use std::env;
+
+    #[test]
+    fn test_batch() -> Result<(), BatchError> {
+        let dir = env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("alice.txt"),
+            "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n",
+        )
+        .unwrap();
+        fs::write(dir.join("bob.txt"), "X1\n").unwrap();
+        fs::write(dir.join("notes.md"), "not an input").unwrap();
+
+        let files = inputs(&dir)?;
+        assert_eq!(files, [dir.join("alice.txt"), dir.join("bob.txt")]);
+
+        let day = registry::find(2025, 1).unwrap();
+        let batch = Batch {
+            entries: solve(day, &[Part::One], &files, Runner::default()),
+            total: Duration::ZERO,
+        };
+        assert_eq!(batch.entries[0].answer(), Some(&Answer::Int(3)));
+        assert_eq!(batch.entries[1].label(), "fail");
+
+        let csv = batch.render(Format::Csv);
+        assert!(csv.starts_with("input,part,status,answer,seconds,detail\nalice.txt,1,solved,3,"));
+        assert!(csv.contains("\nbob.txt,1,fail,,"));
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(inputs(&dir), Err(BatchError::Read { .. })));
+        Ok(())
+    }
+}
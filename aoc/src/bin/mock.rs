@@ -0,0 +1,35 @@
+use aoc::mock::{self, Mock, Mode};
+use clap::Parser;
+use miette::Result;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+#[command(
+    version,
+    about = "Stand in for adventofcode.com, serving fixture files to test the client against"
+)]
+struct Cli {
+    /// Address to listen on, port 0 picks any free one
+    #[arg(long, default_value = "127.0.0.1:8026")]
+    addr: String,
+    /// Directory of the fixtures, `<year>/day-NN/input.txt` and so on
+    #[arg(long, default_value_os_t = mock::fixtures())]
+    fixtures: PathBuf,
+    /// Only accept this session cookie, instead of any
+    #[arg(long)]
+    session: Option<String>,
+    /// Failure to start in, switched later with `PUT /mock/mode`
+    #[arg(long, value_enum, default_value_t)]
+    mode: Mode,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let mut stand_in = Mock::new(cli.fixtures).with_mode(cli.mode);
+    if let Some(session) = cli.session {
+        stand_in = stand_in.with_session(session);
+    }
+    mock::serve(&*cli.addr, stand_in, |addr| {
+        println!("listening on http://{addr}");
+    })
+}
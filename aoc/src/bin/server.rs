@@ -0,0 +1,43 @@
+use aoc::{
+    run::{self, Runner},
+    server, worker,
+};
+use clap::{Parser, Subcommand};
+use miette::Result;
+use std::time::Duration;
+
+#[derive(Debug, Parser)]
+#[command(
+    version,
+    about = "Serve the Advent of Code solutions of every year over HTTP"
+)]
+struct Cli {
+    /// Address to listen on, port 0 picks any free one
+    #[arg(long, default_value = "127.0.0.1:8025")]
+    addr: String,
+    /// Give up on a part after this long, `0` to never give up
+    #[arg(long, value_parser = run::parse_budget, default_value = "10s")]
+    timeout: Duration,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    #[command(name = worker::COMMAND, hide = true)]
+    Worker(worker::Args),
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    if let Some(Command::Worker(args)) = cli.command {
+        return worker::serve(args);
+    }
+    let runner = Runner {
+        budget: run::limit(cli.timeout),
+        ..Runner::default()
+    };
+    server::serve(&*cli.addr, runner, |addr| {
+        println!("listening on http://{addr}");
+    })
+}
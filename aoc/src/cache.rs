@@ -0,0 +1,203 @@
+//! Answers computed before, reused as long as neither the input nor the
+//! sources of the day changed.
+//!
+//! Entries are keyed by year, day, part and the SHA-256 of the input and of
+//! the day crate's sources, its path dependencies and its year's lock file
+//! (hashed by `build.rs`), so several inputs of a part are cached side by side
+//! and editing the sources invalidates them. The cache lives in the
+//! git-ignored `.aoc/` directory at the root of the repository.
+
+use crate::registry::{self, Answer, Day, Part};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{collections::BTreeMap, fmt::Write, fs, io, path::PathBuf, sync::Mutex, time::Duration};
+
+mod sources {
+    include!(concat!(env!("OUT_DIR"), "/sources.rs"));
+}
+
+/// Hash of the sources `day` was built from.
+#[must_use]
+pub fn source_hash(day: &Day) -> Option<&'static str> {
+    sources::SOURCES
+        .iter()
+        .find(|&&(year, number, _)| year == day.year && number == day.day)
+        .map(|&(_, _, hash)| hash)
+}
+
+#[must_use]
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub answer: Answer,
+    /// How long computing the answer took.
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Default)]
+pub struct Cache {
+    path: PathBuf,
+    entries: Mutex<BTreeMap<String, Entry>>,
+    /// Ignore what is cached, but still record fresh answers.
+    fresh: bool,
+}
+
+impl Cache {
+    #[must_use]
+    pub fn path() -> PathBuf {
+        registry::root().join(".aoc").join("answers.json")
+    }
+
+    /// Opens the cache; a missing or unreadable file starts an empty one.
+    #[must_use]
+    pub fn open(fresh: bool) -> Self {
+        let path = Self::path();
+        let entries = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+        Self {
+            path,
+            entries: Mutex::new(entries),
+            fresh,
+        }
+    }
+
+    fn prefix(day: &Day, part: Part) -> String {
+        format!("{}/{:02}/{part}/", day.year, day.day)
+    }
+
+    fn key(day: &Day, part: Part, input: &str, source: &str) -> String {
+        format!("{}{}/{source}", Self::prefix(day, part), input_hash(input))
+    }
+
+    /// The cached answer of `part`, if it was computed from the same input and
+    /// sources.
+    ///
+    /// # Panics
+    ///
+    /// When another thread panicked while holding the cache.
+    #[must_use]
+    pub fn get(&self, day: &Day, part: Part, input: &str) -> Option<Entry> {
+        if self.fresh {
+            return None;
+        }
+        let source = source_hash(day)?;
+        let entries = self.entries.lock().unwrap();
+        entries.get(&Self::key(day, part, input, source)).cloned()
+    }
+
+    /// Records the answer of `part` on `input`, dropping the entries of the
+    /// part computed by other sources, which can't be hit anymore.
+    ///
+    /// # Panics
+    ///
+    /// When another thread panicked while holding the cache.
+    pub fn put(&self, day: &Day, part: Part, input: &str, answer: &Answer, elapsed: Duration) {
+        let Some(source) = source_hash(day) else {
+            return;
+        };
+        let entry = Entry {
+            answer: answer.clone(),
+            elapsed,
+        };
+        let prefix = Self::prefix(day, part);
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|key, _| {
+            key.strip_prefix(&prefix)
+                .is_none_or(|rest| rest.ends_with(&format!("/{source}")))
+        });
+        entries.insert(Self::key(day, part, input, source), entry);
+    }
+
+    /// # Errors
+    ///
+    /// When the cache file can't be written.
+    ///
+    /// # Panics
+    ///
+    /// When another thread panicked while holding the cache.
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(&*self.entries.lock().unwrap())
+            .map_err(io::Error::other)?;
+        fs::write(&self.path, json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_is_keyed_by_input_and_source() {
+        let cache = Cache::default();
+        let day = registry::find(2025, 1).unwrap();
+        assert!(source_hash(day).is_some());
+
+        cache.put(
+            day,
+            Part::One,
+            "L68\n",
+            &Answer::Int(0),
+            Duration::from_millis(1),
+        );
+        assert_eq!(
+            cache.get(day, Part::One, "L68\n").unwrap().answer,
+            Answer::Int(0)
+        );
+        assert!(cache.get(day, Part::One, "L69\n").is_none());
+        assert!(cache.get(day, Part::Two, "L68\n").is_none());
+
+        cache.put(day, Part::One, "L69\n", &Answer::Int(1), Duration::ZERO);
+        assert_eq!(
+            cache.get(day, Part::One, "L68\n").unwrap().answer,
+            Answer::Int(0)
+        );
+        assert_eq!(
+            cache.get(day, Part::One, "L69\n").unwrap().answer,
+            Answer::Int(1)
+        );
+
+        let stale = Cache::key(day, Part::One, "L68\n", "stale");
+        let entry = {
+            let mut entries = cache.entries.lock().unwrap();
+            let entry = entries
+                .remove(&Cache::key(
+                    day,
+                    Part::One,
+                    "L68\n",
+                    source_hash(day).unwrap(),
+                ))
+                .unwrap();
+            entries.insert(stale.clone(), entry.clone());
+            entry
+        };
+        assert!(cache.get(day, Part::One, "L68\n").is_none());
+
+        cache.put(day, Part::One, "L68\n", &entry.answer, entry.elapsed);
+        assert!(!cache.entries.lock().unwrap().contains_key(&stale));
+        assert!(cache.get(day, Part::One, "L69\n").is_some());
+    }
+
+    #[test]
+    fn test_fresh_ignores_cached_answers() {
+        let cache = Cache {
+            fresh: true,
+            ..Cache::default()
+        };
+        let day = registry::find(2025, 1).unwrap();
+        cache.put(day, Part::One, "L68\n", &Answer::Int(0), Duration::ZERO);
+        assert!(cache.get(day, Part::One, "L68\n").is_none());
+    }
+}
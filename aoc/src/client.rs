@@ -0,0 +1,235 @@
+//! Talking to adventofcode.com, or to whatever stands in for it.
+//!
+//! Every request carries the session cookie and a User-Agent naming this
+//! repository, as the site asks of automated tools. Requests are spaced at
+//! least [`INTERVAL`] apart, across processes too: the time of the last one is
+//! kept in `.aoc/last-request`.
+
+use crate::registry;
+use miette::Diagnostic;
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+use ureq::Agent;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable replacing [`BASE_URL`], e.g. with a local stand-in.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Environment variables holding the session cookie, in the order they are
+/// looked up. `SESSION` is also read from a `.env` file.
+pub const SESSION_ENV: [&str; 2] = ["AOC_SESSION", "SESSION"];
+
+pub const USER_AGENT: &str = concat!(
+    "github.com/kristoferssolo/Advent-of-Code aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// Least time between two requests.
+pub const INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum ClientError {
+    #[error("no adventofcode.com session cookie")]
+    #[diagnostic(
+        code(aoc::client::session),
+        help(
+            "set `AOC_SESSION`, or `SESSION` in a `.env` file, to the `session` cookie of adventofcode.com"
+        )
+    )]
+    NoSession,
+    #[error("{url} replied {status}")]
+    #[diagnostic(
+        code(aoc::client::status),
+        help("{}", status_help(*.status))
+    )]
+    Status { url: String, status: u16 },
+    #[error("failed to reach {url}")]
+    #[diagnostic(code(aoc::client::transport))]
+    Transport {
+        url: String,
+        #[source]
+        error: Box<ureq::Transport>,
+    },
+    #[error("failed to read the reply of {url}")]
+    #[diagnostic(code(aoc::client::read))]
+    Read {
+        url: String,
+        #[source]
+        error: io::Error,
+    },
+}
+
+const fn status_help(status: u16) -> &'static str {
+    match status {
+        400 | 401 | 500 => {
+            "the session cookie is likely expired, log in again and copy a fresh one"
+        }
+        404 => "the puzzle may not be unlocked yet",
+        _ => "adventofcode.com may be down, try again later",
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    interval: Duration,
+    /// File remembering when the last request was sent, `None` to only
+    /// space requests of this client.
+    stamp: Option<PathBuf>,
+}
+
+impl Client {
+    /// A client of [`BASE_URL`] authenticated with `session`.
+    #[must_use]
+    pub fn new(session: impl Into<String>) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: BASE_URL.to_string(),
+            session: session.into(),
+            interval: INTERVAL,
+            stamp: Some(registry::root().join(".aoc").join("last-request")),
+        }
+    }
+
+    /// A client configured from the environment, see [`SESSION_ENV`] and
+    /// [`BASE_URL_ENV`].
+    ///
+    /// # Errors
+    ///
+    /// When no session cookie is set.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = session().ok_or(ClientError::NoSession)?;
+        let client = Self::new(session);
+        Ok(match env::var(BASE_URL_ENV) {
+            Ok(url) if !url.is_empty() => client.with_base_url(url),
+            _ => client,
+        })
+    }
+
+    #[must_use]
+    pub fn with_base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    #[must_use]
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    #[must_use]
+    pub fn with_stamp(mut self, stamp: Option<PathBuf>) -> Self {
+        self.stamp = stamp;
+        self
+    }
+
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// `GET`s `path`, e.g. `/2025/day/1/input`, returning the body.
+    ///
+    /// # Errors
+    ///
+    /// When the server can't be reached or doesn't reply `200 OK`.
+    pub fn get(&self, path: &str) -> Result<String, ClientError> {
+        let url = format!("{}{path}", self.base_url);
+        self.throttle();
+        let request = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session));
+        read(&url, request.call())
+    }
+
+    /// `POST`s the `form` fields to `path`, returning the body.
+    ///
+    /// # Errors
+    ///
+    /// When the server can't be reached or doesn't reply `200 OK`.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let url = format!("{}{path}", self.base_url);
+        self.throttle();
+        let request = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session));
+        read(&url, request.send_form(form))
+    }
+
+    /// Sleeps until [`Self::interval`](Client::with_interval) passed since
+    /// the last request, then records this one.
+    fn throttle(&self) {
+        let Some(stamp) = &self.stamp else {
+            return;
+        };
+        if let Some(last) = last_request(stamp) {
+            let since = SystemTime::now().duration_since(last).unwrap_or_default();
+            if let Some(wait) = self.interval.checked_sub(since) {
+                thread::sleep(wait);
+            }
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        if let Some(dir) = stamp.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        let _ = fs::write(stamp, now.to_string());
+    }
+}
+
+fn last_request(stamp: &Path) -> Option<SystemTime> {
+    let millis = fs::read_to_string(stamp).ok()?.trim().parse().ok()?;
+    UNIX_EPOCH.checked_add(Duration::from_millis(millis))
+}
+
+fn read(url: &str, reply: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    match reply {
+        Ok(response) => response.into_string().map_err(|error| ClientError::Read {
+            url: url.to_string(),
+            error,
+        }),
+        Err(ureq::Error::Status(status, _)) => Err(ClientError::Status {
+            url: url.to_string(),
+            status,
+        }),
+        Err(ureq::Error::Transport(error)) => Err(ClientError::Transport {
+            url: url.to_string(),
+            error: Box::new(error),
+        }),
+    }
+}
+
+/// The session cookie from [`SESSION_ENV`], then from `SESSION` in the `.env`
+/// of the working directory or of the repository root.
+fn session() -> Option<String> {
+    SESSION_ENV
+        .iter()
+        .find_map(|var| env::var(var).ok())
+        .or_else(|| {
+            [PathBuf::from(".env"), registry::root().join(".env")]
+                .iter()
+                .find_map(|path| dotenv(path, "SESSION"))
+        })
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+}
+
+fn dotenv(path: &Path, key: &str) -> Option<String> {
+    fs::read_to_string(path).ok()?.lines().find_map(|line| {
+        let (name, value) = line.split_once('=')?;
+        (name.trim() == key).then(|| value.trim().trim_matches('"').to_string())
+    })
+}
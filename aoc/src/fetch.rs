@@ -0,0 +1,403 @@
+//! Downloading puzzle inputs and descriptions into the day crates.
+//!
+//! Inputs are kept in `.aoc/inputs/<year>/day-NN.txt`, so scaffolding a day
+//! again, or on another checkout of the same machine, doesn't ask the site
+//! twice for an input that never changes. Descriptions aren't kept: the page
+//! grows a second part once the first is solved.
+//!
+//! Everything fetched is also saved to the [`Archive`], which outlives the
+//! cache: an input found there isn't downloaded again either.
+
+use crate::{
+    archive::{Archive, ArchiveError},
+    client::{Client, ClientError},
+    input, puzzle,
+    registry::{self, Part},
+};
+use aoc_core::examples;
+use miette::Diagnostic;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum FetchError {
+    #[error("{} already exists", .path.display())]
+    #[diagnostic(
+        code(aoc::fetch::exists),
+        help("inputs are never overwritten, delete it first to fetch it again")
+    )]
+    Exists { path: PathBuf },
+    #[error("no crate for {year} day {day:02} at {}", .dir.display())]
+    #[diagnostic(
+        code(aoc::fetch::no_day),
+        help("scaffold it first with `just create day-{day:02}`")
+    )]
+    NoDay { year: u16, day: u8, dir: PathBuf },
+    #[error("failed to write {}", .path.display())]
+    #[diagnostic(code(aoc::fetch::write))]
+    Write {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Client(#[from] ClientError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Archive(#[from] ArchiveError),
+}
+
+/// Where downloaded inputs are kept.
+#[must_use]
+pub fn cache_dir() -> PathBuf {
+    registry::root().join(".aoc").join("inputs")
+}
+
+/// The crate directory of a day, registered or not.
+#[must_use]
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    registry::root()
+        .join(year.to_string())
+        .join(format!("day-{day:02}"))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetched {
+    pub path: PathBuf,
+    /// Whether the input came from the cache rather than the site.
+    pub cached: bool,
+}
+
+/// The input of `day` of `year`, from `cache` or the `archive` when it was
+/// downloaded before.
+///
+/// # Errors
+///
+/// When downloading fails or the download can't be cached or archived.
+pub fn download(
+    client: &Client,
+    cache: &Path,
+    archive: &Archive,
+    year: u16,
+    day: u8,
+) -> Result<(String, bool), FetchError> {
+    let cached = cache
+        .join(year.to_string())
+        .join(format!("day-{day:02}.txt"));
+    if let Ok(text) = fs::read_to_string(&cached) {
+        archive.save_input(year, day, &text)?;
+        return Ok((text, true));
+    }
+    if let Some(text) = archive.input(year, day)? {
+        write(&cached, &text)?;
+        return Ok((text, true));
+    }
+    let text = client.get(&format!("/{year}/day/{day}/input"))?;
+    write(&cached, &text)?;
+    archive.save_input(year, day, &text)?;
+    Ok((text, false))
+}
+
+/// Saves the input of `day` of `year` as the [`input::SHARED`] input of the
+/// crate in `dir`.
+///
+/// # Errors
+///
+/// When `dir` doesn't exist, already holds an input, or the input can't be
+/// downloaded, written or archived.
+pub fn fetch(
+    client: &Client,
+    cache: &Path,
+    archive: &Archive,
+    year: u16,
+    day: u8,
+    dir: &Path,
+) -> Result<Fetched, FetchError> {
+    if !dir.is_dir() {
+        return Err(FetchError::NoDay {
+            year,
+            day,
+            dir: dir.to_path_buf(),
+        });
+    }
+    if let Some(path) = ["input1.txt", "input2.txt", input::SHARED]
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+    {
+        return Err(FetchError::Exists { path });
+    }
+    let (text, cached) = download(client, cache, archive, year, day)?;
+    let path = dir.join(input::SHARED);
+    write(&path, &text)?;
+    Ok(Fetched { path, cached })
+}
+
+/// Name of the git-ignored description of a day.
+pub const PUZZLE: &str = "puzzle.md";
+
+/// Saves the description of `day` of `year` as the [`PUZZLE`] of the crate in
+/// `dir`, returning the files written.
+///
+/// The example of each part and the answer it expects become fixtures in its
+/// [`examples::DIR`]. Fixtures that exist already are kept, while the
+/// `archive` always gets the latest description and examples.
+///
+/// # Errors
+///
+/// When `dir` doesn't exist, or the page can't be downloaded, written or
+/// archived.
+pub fn puzzle(
+    client: &Client,
+    archive: &Archive,
+    year: u16,
+    day: u8,
+    dir: &Path,
+) -> Result<Vec<PathBuf>, FetchError> {
+    if !dir.is_dir() {
+        return Err(FetchError::NoDay {
+            year,
+            day,
+            dir: dir.to_path_buf(),
+        });
+    }
+    let articles = puzzle::parse(&client.get(&format!("/{year}/day/{day}"))?);
+    let path = dir.join(PUZZLE);
+    let document = puzzle::document(&articles);
+    write(&path, &document)?;
+    archive.save_puzzle(year, day, &document)?;
+    let mut written = vec![path];
+
+    let fixtures = dir.join(examples::DIR);
+    // part 2 usually reuses the example of part 1
+    let mut first: Option<(String, String)> = None;
+    for (part, article) in Part::ALL.into_iter().zip(&articles) {
+        let Some(example) = article
+            .example
+            .clone()
+            .or_else(|| first.as_ref().map(|(_, example)| example.clone()))
+        else {
+            continue;
+        };
+        let name = match &first {
+            None => "example".to_string(),
+            Some((name, shared)) if *shared == example => name.clone(),
+            Some(_) => format!("example-{part}"),
+        };
+        archive.save_example(year, day, part, &name, &example, article.answer.as_ref())?;
+        let new = |path: PathBuf| (!path.exists()).then_some(path);
+        if let Some(path) = new(examples::input_path(&fixtures, &name)) {
+            write(&path, &example)?;
+            written.push(path);
+        }
+        if let (Some(answer), Some(path)) = (
+            &article.answer,
+            new(examples::answer_path(&fixtures, &name, part)),
+        ) {
+            write(&path, &format!("{answer}\n"))?;
+            written.push(path);
+        }
+        first.get_or_insert((name, example));
+    }
+    Ok(written)
+}
+
+/// How long a downloaded leaderboard is reused, the site asks not to be
+/// asked more often.
+pub const LEADERBOARD_TTL: Duration = Duration::from_mins(15);
+
+/// The JSON of the private leaderboard `id` of `year`, from `cache` when it
+/// was downloaded less than [`LEADERBOARD_TTL`] ago.
+///
+/// # Errors
+///
+/// When downloading fails or the download can't be cached.
+pub fn leaderboard(
+    client: &Client,
+    cache: &Path,
+    year: u16,
+    id: u64,
+) -> Result<String, FetchError> {
+    let cached = cache.join(format!("leaderboard-{year}-{id}.json"));
+    let fresh = fs::metadata(&cached)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < LEADERBOARD_TTL);
+    if fresh && let Ok(json) = fs::read_to_string(&cached) {
+        return Ok(json);
+    }
+    let json = client.get(&format!("/{year}/leaderboard/private/view/{id}.json"))?;
+    write(&cached, &json)?;
+    Ok(json)
+}
+
+fn write(path: &Path, text: &str) -> Result<(), FetchError> {
+    let error = |error| FetchError::Write {
+        path: path.to_path_buf(),
+        error,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(error)?;
+    }
+    fs::write(path, text).map_err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::USER_AGENT;
+    use std::{
+        env,
+        sync::{Arc, Mutex},
+        thread,
+        time::{Duration, Instant},
+    };
+    use tiny_http::{Response, Server};
+
+    /// Cookie and User-Agent of every request.
+    type Seen = Arc<Mutex<Vec<(String, String)>>>;
+
+    const PAGE: &str = r"<html><body><main>
+<article class='day-desc'><h2>--- Day 1 ---</h2><pre><code>L68
+R48
+</code></pre><p>The password is <code><em>3</em></code>.</p></article>
+<article class='day-desc'><h2>--- Part Two ---</h2><p>Now it is <code><em>6</em></code>.</p></article>
+</main></body></html>";
+
+    /// Serves `/2025/day/1/input` and its puzzle page, and records every
+    /// request.
+    fn stand_in() -> (String, Seen) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = Arc::clone(&requests);
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let header = |name: &'static str| {
+                    request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv(name))
+                        .map(|header| header.value.to_string())
+                        .unwrap_or_default()
+                };
+                seen.lock()
+                    .unwrap()
+                    .push((header("Cookie"), header("User-Agent")));
+                let response = match request.url() {
+                    "/2025/day/1/input" => Response::from_string("L68\nR48\n"),
+                    "/2025/day/1" => Response::from_string(PAGE),
+                    _ => Response::from_string("Not Found").with_status_code(404),
+                };
+                let _ = request.respond(response);
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn test_fetch() -> Result<(), FetchError> {
+        let (url, requests) = stand_in();
+        let root = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let (cache, dir) = (root.join("cache"), root.join("day-01"));
+        fs::create_dir_all(&dir).unwrap();
+        let client = Client::new("abc")
+            .with_base_url(format!("{url}/"))
+            .with_interval(Duration::from_millis(200))
+            .with_stamp(Some(root.join("last-request")));
+
+        let archive = Archive::memory()?;
+        let start = Instant::now();
+        let fetched = fetch(&client, &cache, &archive, 2025, 1, &dir)?;
+        assert_eq!(fetched.path, dir.join("input.txt"));
+        assert!(!fetched.cached);
+        assert_eq!(fs::read_to_string(&fetched.path).unwrap(), "L68\nR48\n");
+        assert!(matches!(
+            fetch(&client, &cache, &archive, 2025, 1, &dir),
+            Err(FetchError::Exists { .. })
+        ));
+
+        fs::remove_file(&fetched.path).unwrap();
+        assert!(fetch(&client, &cache, &archive, 2025, 1, &dir)?.cached);
+        // the archive outlives the cache
+        fs::remove_file(&fetched.path).unwrap();
+        fs::remove_dir_all(&cache).unwrap();
+        assert!(fetch(&client, &cache, &archive, 2025, 1, &dir)?.cached);
+        assert_eq!(archive.input(2025, 1)?.as_deref(), Some("L68\nR48\n"));
+        fs::create_dir_all(root.join("day-02")).unwrap();
+        assert!(matches!(
+            fetch(&client, &cache, &archive, 2025, 2, &root.join("day-02")),
+            Err(FetchError::Client(ClientError::Status { status: 404, .. }))
+        ));
+        // the second request waited for the interval to pass
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert!(matches!(
+            fetch(&client, &cache, &archive, 2025, 3, &root.join("day-03")),
+            Err(FetchError::NoDay { .. })
+        ));
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].0, "session=abc");
+        assert_eq!(requests[0].1, USER_AGENT);
+
+        fs::remove_dir_all(root).unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_puzzle() -> Result<(), FetchError> {
+        let (url, _) = stand_in();
+        let dir = env::temp_dir().join(format!("aoc-puzzle-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let client = Client::new("abc").with_base_url(url).with_stamp(None);
+        let archive = Archive::memory()?;
+
+        let written = puzzle(&client, &archive, 2025, 1, &dir)?;
+        let fixtures = dir.join("examples");
+        assert_eq!(
+            written,
+            [
+                dir.join("puzzle.md"),
+                fixtures.join("example.txt"),
+                fixtures.join("example.part1"),
+                fixtures.join("example.part2"),
+            ]
+        );
+        assert!(
+            fs::read_to_string(dir.join("puzzle.md"))
+                .unwrap()
+                .starts_with("## --- Day 1 ---")
+        );
+        let read = |path: PathBuf| fs::read_to_string(path).unwrap();
+        assert_eq!(
+            read(examples::input_path(&fixtures, "example")),
+            "L68\nR48\n"
+        );
+        assert_eq!(
+            read(examples::answer_path(&fixtures, "example", Part::Two)),
+            "6\n"
+        );
+        let archived = &archive.examples(2025, 1, Part::Two)?[0];
+        assert_eq!(
+            (archived.input.as_str(), &archived.answer),
+            ("L68\nR48\n", &6.into())
+        );
+        assert!(archive.puzzle(2025, 1)?.is_some());
+
+        // fixtures are never overwritten, the description always is
+        assert_eq!(
+            puzzle(&client, &archive, 2025, 1, &dir)?,
+            [dir.join("puzzle.md")]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+//! Locating the puzzle input of a registered day.
+
+use crate::{
+    registry::{Day, Part},
+    vault,
+};
+pub use aoc_core::input::{Input, InputError, SHARED, overridden};
+use std::path::PathBuf;
+
+/// `input1.txt`/`input2.txt` in the day's crate, falling back to the single
+/// [`SHARED`] `input.txt` both parts read, then to its encrypted copy.
+#[must_use]
+pub fn defaults(day: &Day, part: Part) -> Vec<PathBuf> {
+    let dir = day.dir();
+    let mut paths = vec![dir.join(format!("input{part}.txt")), dir.join(SHARED)];
+    paths.extend(vault::input_path(&dir));
+    paths
+}
+
+/// Reads the day's default input file.
+///
+/// # Errors
+///
+/// When none of the [`defaults`] can be read.
+pub fn read(day: &Day, part: Part) -> Result<Input, InputError> {
+    aoc_core::input::from_defaults(&defaults(day, part))
+}
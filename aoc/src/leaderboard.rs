@@ -0,0 +1,245 @@
+//! A private leaderboard, as the JSON the site serves it in, rendered for the
+//! terminal: the standings with a star per day, or one day's star times with
+//! how long part 2 took after part 1.
+//!
+//! Times are shown in UTC-5, the time zone puzzles unlock in.
+
+use crate::report::table;
+use jiff::{SignedDuration, Timestamp, tz::TimeZone};
+use miette::Diagnostic;
+use serde::Deserialize;
+use std::{collections::BTreeMap, fmt::Write};
+use thiserror::Error;
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("failed to read the leaderboard")]
+#[diagnostic(
+    code(aoc::leaderboard::parse),
+    help("expected the JSON of `/<year>/leaderboard/private/view/<id>.json`")
+)]
+pub struct LeaderboardError(#[from] serde_json::Error);
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// Unix time of the last star, `0` without any.
+    pub last_star_ts: i64,
+    /// Stars by day, then by part.
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Star {
+    /// Unix time the star was earned.
+    pub get_star_ts: i64,
+}
+
+impl Member {
+    #[must_use]
+    pub fn name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// When the star of `part` of `day` was earned.
+    #[must_use]
+    pub fn star(&self, day: u8, part: u8) -> Option<Timestamp> {
+        let star = self.completion_day_level.get(&day)?.get(&part)?;
+        Timestamp::from_second(star.get_star_ts).ok()
+    }
+
+    /// How long part 2 of `day` took after part 1.
+    #[must_use]
+    pub fn delta(&self, day: u8) -> Option<SignedDuration> {
+        Some(self.star(day, 2)?.duration_since(self.star(day, 1)?))
+    }
+}
+
+impl Leaderboard {
+    /// # Errors
+    ///
+    /// When `json` isn't a private leaderboard.
+    pub fn parse(json: &str) -> Result<Self, LeaderboardError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Members by score, ties going to who got their last star first.
+    #[must_use]
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members = self.members.values().collect::<Vec<_>>();
+        members.sort_by_key(|member| (u64::MAX - member.local_score, member.last_star_ts));
+        members
+    }
+
+    /// The days anyone earned a star on.
+    fn days(&self) -> u8 {
+        self.members
+            .values()
+            .filter_map(|member| member.completion_day_level.keys().max().copied())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The standings, with `*` for both stars of a day, `+` for part 1 only
+    /// and `.` for none.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let days = self.days();
+        let rows = self
+            .standings()
+            .iter()
+            .enumerate()
+            .map(|(rank, member)| {
+                let strip = (1..=days)
+                    .map(|day| match member.completion_day_level.get(&day) {
+                        Some(parts) if parts.len() == 2 => '*',
+                        Some(_) => '+',
+                        None => '.',
+                    })
+                    .collect::<String>();
+                [
+                    format!("{})", rank + 1),
+                    member.local_score.to_string(),
+                    member.stars.to_string(),
+                    strip,
+                    member.name(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let mut out = table(&["", "score", "stars", "days", "name"], &rows);
+        let _ = write!(out, "{} members, event {}", self.members.len(), self.event);
+        out
+    }
+
+    /// When everyone earned the stars of `day`, fastest to finish part 2
+    /// first.
+    #[must_use]
+    pub fn render_day(&self, day: u8) -> String {
+        let mut members = self
+            .members
+            .values()
+            .filter(|member| member.star(day, 1).is_some())
+            .collect::<Vec<_>>();
+        members.sort_by_key(|member| {
+            (
+                member.star(day, 2).is_none(),
+                member.star(day, 2),
+                member.star(day, 1),
+            )
+        });
+        let rows = members
+            .iter()
+            .map(|member| {
+                [
+                    member.name(),
+                    member.star(day, 1).map(time).unwrap_or_default(),
+                    member.star(day, 2).map(time).unwrap_or_default(),
+                    member.delta(day).map(duration).unwrap_or_default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let mut out = table(&["name", "part 1", "part 2", "delta"], &rows);
+        let _ = write!(
+            out,
+            "{} of {} members started day {day}",
+            members.len(),
+            self.members.len()
+        );
+        out
+    }
+}
+
+/// `Dec 01 00:04:12` in the time zone puzzles unlock in.
+fn time(timestamp: Timestamp) -> String {
+    let eastern = TimeZone::fixed(jiff::tz::offset(-5));
+    timestamp
+        .to_zoned(eastern)
+        .strftime("%b %d %H:%M:%S")
+        .to_string()
+}
+
+/// `1:02:03`, hours adding up past a day.
+fn duration(duration: SignedDuration) -> String {
+    let seconds = duration.as_secs();
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JSON: &str = r#"{
+        "event": "2025",
+        "owner_id": 1,
+        "day1_ts": 1764565200,
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 5, "global_score": 0,
+                "last_star_ts": 1764652000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1764565452, "star_index": 10},
+                        "2": {"get_star_ts": 1764566172, "star_index": 20}
+                    },
+                    "2": {"1": {"get_star_ts": 1764652000, "star_index": 30}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 5, "global_score": 0,
+                "last_star_ts": 1764570000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1764566000, "star_index": 11},
+                        "2": {"get_star_ts": 1764570000, "star_index": 21}
+                    }
+                }
+            },
+            "3": {
+                "id": 3, "name": "Carol", "stars": 0, "local_score": 0, "global_score": 0,
+                "last_star_ts": 0, "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_leaderboard() -> Result<(), LeaderboardError> {
+        let leaderboard = Leaderboard::parse(JSON)?;
+        let names = leaderboard
+            .standings()
+            .iter()
+            .map(|member| member.name())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["(anonymous user #2)", "Alice", "Carol"]);
+
+        let alice = &leaderboard.members["1"];
+        assert_eq!(alice.delta(1), Some(SignedDuration::from_mins(12)));
+        assert_eq!(alice.delta(2), None);
+
+        let standings = leaderboard.render();
+        assert!(standings.contains("2)  5      3      *+    Alice"));
+        assert!(standings.contains("3)  0      0      ..    Carol"));
+
+        let day = leaderboard.render_day(1);
+        assert!(day.contains("Alice                Dec 01 00:04:12  Dec 01 00:16:12  0:12:00"));
+        assert!(day.ends_with("2 of 3 members started day 1"));
+        assert!(Leaderboard::parse("{}").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+pub mod answers;
+pub mod archive;
+pub mod batch;
+pub mod cache;
+pub mod client;
+pub mod fetch;
+pub mod input;
+pub mod leaderboard;
+pub mod mock;
+pub mod puzzle;
+pub mod registry;
+pub mod report;
+pub mod run;
+pub mod server;
+pub mod status;
+pub mod submit;
+pub mod unlock;
+pub mod vault;
+pub mod verify;
+pub mod worker;
@@ -0,0 +1,619 @@
+use aoc::{
+    archive::Archive,
+    batch::{self, Batch},
+    cache::Cache,
+    client::Client,
+    fetch, input,
+    leaderboard::Leaderboard,
+    registry::{self, Answer, Day, Part, Preset, Solved},
+    report::{Format, Report},
+    run::{self, Failure, Runner},
+    status::Matrix,
+    submit,
+    unlock::{self, Clock, SystemClock},
+    vault::{self, Key},
+    verify, worker,
+};
+use aoc_core::solution::Timings;
+use clap::{Args, Parser, Subcommand};
+use miette::{Context, IntoDiagnostic, Result, miette};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Run the Advent of Code solutions of every year")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run a single part, both parts of a day or, with `--all`, a whole year
+    /// in parallel
+    Run(RunArgs),
+    /// Check every implemented part against the recorded `answers.toml`
+    Verify(VerifyArgs),
+    /// Solve a day against every `*.txt` input in a directory, one row per
+    /// file and part
+    Batch(BatchArgs),
+    /// Show which parts are solved, still `todo!()`, missing or failing to
+    /// verify, as a year × day × part matrix
+    Status(StatusArgs),
+    /// Download a day's puzzle input into its crate, never overwriting one,
+    /// or with `--puzzle` its description and examples
+    Fetch(FetchArgs),
+    /// Submit an answer, computing it when left out; known-wrong answers are
+    /// refused without asking the site
+    Submit(SubmitArgs),
+    /// Show a private leaderboard, or with `--day` when everyone earned that
+    /// day's stars
+    Leaderboard(LeaderboardArgs),
+    /// Show when the next puzzle unlocks, or with `--wait` wait for it, then
+    /// scaffold its crate and fetch its input and description
+    Unlock(UnlockArgs),
+    /// Encrypt a year's inputs and answers into `inputs/<year>/`, to be
+    /// committed
+    Encrypt(VaultArgs),
+    /// Decrypt a year's committed inputs and answers into plaintext files,
+    /// never overwriting one
+    Decrypt(VaultArgs),
+    /// Export the puzzle archive of descriptions, inputs, examples, guesses
+    /// and accepted answers as JSON, for backup
+    Export {
+        /// Write to this file instead of standard output
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    #[command(name = worker::COMMAND, hide = true)]
+    Worker(worker::Args),
+    /// List the registered days and their parts
+    List { year: Option<u16> },
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    year: u16,
+    day: Option<u8>,
+    part: Option<Part>,
+    /// Run every registered day of the year
+    #[arg(long, conflicts_with_all = ["day", "part", "input"])]
+    all: bool,
+    /// Input file of a single-day run, `-` for stdin; `AOC_INPUT` works too
+    #[arg(long, short)]
+    input: Option<String>,
+    /// Give up on a part after this long, e.g. `30s`; `0` to never give up.
+    /// Defaults to 10s with `--all` and to no limit otherwise
+    #[arg(long, value_parser = run::parse_budget)]
+    timeout: Option<Duration>,
+    /// How to report a whole-year run
+    #[arg(long, value_enum, default_value_t, requires = "all")]
+    format: Format,
+    /// Recompute answers even when they are cached
+    #[arg(long)]
+    fresh: bool,
+    /// Parameters of the days that have any, `example` for an example input
+    #[arg(long, default_value_t)]
+    preset: Preset,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// Only verify this year
+    year: Option<u16>,
+    /// Only verify this day of the year
+    #[arg(requires = "year")]
+    day: Option<u8>,
+    /// Give up on a part after this long, `0` to never give up
+    #[arg(long, value_parser = run::parse_budget, default_value = "10s")]
+    timeout: Duration,
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Recompute answers even when they are cached
+    #[arg(long)]
+    fresh: bool,
+}
+
+#[derive(Debug, Args)]
+struct BatchArgs {
+    year: u16,
+    day: u8,
+    /// Directory of inputs, e.g. `inputs/2025/day-10`
+    dir: PathBuf,
+    /// Only solve this part
+    #[arg(long, short)]
+    part: Option<Part>,
+    /// Give up on a part after this long, `0` to never give up
+    #[arg(long, value_parser = run::parse_budget, default_value = "10s")]
+    timeout: Duration,
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Recompute answers even when they are cached
+    #[arg(long)]
+    fresh: bool,
+    /// Parameters of the days that have any
+    #[arg(long, default_value_t)]
+    preset: Preset,
+}
+
+#[derive(Debug, Args)]
+struct StatusArgs {
+    /// Only show this year
+    year: Option<u16>,
+    /// Give up on a part after this long, `0` to never give up
+    #[arg(long, value_parser = run::parse_budget, default_value = "10s")]
+    timeout: Duration,
+    /// Recompute answers even when they are cached
+    #[arg(long)]
+    fresh: bool,
+}
+
+#[derive(Debug, Args)]
+struct FetchArgs {
+    year: u16,
+    /// `1`, `01` or `day-01`
+    #[arg(value_parser = registry::parse_day)]
+    day: u8,
+    /// Download the description as `puzzle.md` instead, and each part's
+    /// example into `examples/` for the tests; fetch again after solving part
+    /// 1 for the example of part 2
+    #[arg(long)]
+    puzzle: bool,
+    /// Fetch from this server instead, e.g. a local stand-in; `AOC_BASE_URL`
+    /// works too
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct SubmitArgs {
+    year: u16,
+    /// `1`, `01` or `day-01`
+    #[arg(value_parser = registry::parse_day)]
+    day: u8,
+    part: Part,
+    /// Answer to submit, computed from the day's input when left out
+    answer: Option<String>,
+    /// Input to compute the answer from, `-` for stdin
+    #[arg(long, short, conflicts_with = "answer")]
+    input: Option<String>,
+    /// Submit to this server instead; `AOC_BASE_URL` works too
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct LeaderboardArgs {
+    year: u16,
+    /// Id of the leaderboard, the number in its URL
+    #[arg(required_unless_present = "file")]
+    id: Option<u64>,
+    /// Read the leaderboard's JSON from this file instead of the site
+    #[arg(long, conflicts_with = "id")]
+    file: Option<PathBuf>,
+    /// Show the star times of this day instead of the standings
+    #[arg(long, value_parser = registry::parse_day)]
+    day: Option<u8>,
+    /// Fetch from this server instead; `AOC_BASE_URL` works too
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct UnlockArgs {
+    /// Count down to the unlock, then create the day's crate from the year's
+    /// `daily-template` and fetch its input and description
+    #[arg(long)]
+    wait: bool,
+    /// Fetch from this server instead; `AOC_BASE_URL` works too
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+#[derive(Debug, Args)]
+struct VaultArgs {
+    year: u16,
+    /// Only this day, `1`, `01` or `day-01`
+    #[arg(value_parser = registry::parse_day)]
+    day: Option<u8>,
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run(args) => run(&args),
+        Command::Verify(args) => verify(&args),
+        Command::Batch(args) => batch(&args),
+        Command::Status(args) => status(&args),
+        Command::Fetch(args) => fetch(&args),
+        Command::Submit(args) => submit(&args),
+        Command::Leaderboard(args) => leaderboard(&args),
+        Command::Unlock(args) => wait_and_fetch(&args, &SystemClock),
+        Command::Encrypt(args) => encrypt(&args),
+        Command::Decrypt(args) => decrypt(&args),
+        Command::Export { output } => export(output.as_deref()),
+        Command::Worker(args) => worker::serve(args),
+        Command::List { year } => {
+            for day in registry::all().filter(|day| year.is_none_or(|year| day.year == year)) {
+                let parts = Part::ALL
+                    .iter()
+                    .filter(|&&part| day.solver(part).is_some())
+                    .map(|part| format!("part{part}"))
+                    .collect::<Vec<_>>();
+                println!("{day}  {}", parts.join(" "));
+            }
+            Ok(())
+        }
+    }
+}
+
+fn run(args: &RunArgs) -> Result<()> {
+    let days = select(args.year, args.day, args.all)?;
+    let cache = Cache::open(args.fresh);
+    let runner = Runner {
+        budget: args
+            .timeout
+            .map_or_else(|| args.all.then_some(run::DEFAULT_BUDGET), run::limit),
+        cache: Some(&cache),
+        preset: args.preset,
+    };
+    if args.all {
+        let archive = read_archive();
+        println!(
+            "{}",
+            check(&days, runner, archive.as_ref())?.render(args.format)
+        );
+        save_cache(&cache);
+        return Ok(());
+    }
+    let parts = args
+        .part
+        .map_or_else(|| Part::ALL.to_vec(), |part| vec![part]);
+    // read once up front, standard input can't be read again per part
+    let overridden = if days.len() == 1 {
+        input::overridden(args.input.as_deref())
+    } else {
+        None
+    };
+    for day in &days {
+        for &part in &parts {
+            match &overridden {
+                Some(input) => run_part(day, part, input.as_ref(), runner),
+                None => run_part(day, part, input::read(day, part).as_ref(), runner),
+            }
+        }
+    }
+    save_cache(&cache);
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<()> {
+    let mut days = Vec::new();
+    for year in args
+        .year
+        .map_or_else(|| registry::years().to_vec(), |year| vec![year])
+    {
+        days.extend(select(year, args.day, args.day.is_none())?);
+    }
+    let cache = Cache::open(args.fresh);
+    let runner = Runner {
+        budget: run::limit(args.timeout),
+        cache: Some(&cache),
+        ..Runner::default()
+    };
+    let archive = read_archive();
+    let report = check(&days, runner, archive.as_ref())?;
+    save_cache(&cache);
+    if let Some(archive) = &archive {
+        for check in &report.checks {
+            if let (verify::Status::Pass, Some(answer)) = (&check.status, &check.answer)
+                && let Err(err) = archive.accept(check.day.year, check.day.day, check.part, answer)
+            {
+                eprintln!("warning: {err}, the answers verified aren't archived");
+                break;
+            }
+        }
+    }
+    println!("{}", report.render(args.format));
+    if report.checks.iter().any(|check| check.status.is_failure()) {
+        return Err(miette!("some answers don't match or failed to run"));
+    }
+    Ok(())
+}
+
+fn batch(args: &BatchArgs) -> Result<()> {
+    let day = select(args.year, Some(args.day), false)?[0];
+    let parts = args
+        .part
+        .map_or_else(|| Part::ALL.to_vec(), |part| vec![part]);
+    let files = batch::inputs(&args.dir)?;
+    let cache = Cache::open(args.fresh);
+    let runner = Runner {
+        budget: run::limit(args.timeout),
+        cache: Some(&cache),
+        preset: args.preset,
+    };
+    let start = Instant::now();
+    let entries = batch::solve(day, &parts, &files, runner);
+    let batch = Batch {
+        entries,
+        total: start.elapsed(),
+    };
+    save_cache(&cache);
+    println!("{}", batch.render(args.format));
+    Ok(())
+}
+
+fn status(args: &StatusArgs) -> Result<()> {
+    let years = args
+        .year
+        .map_or_else(|| registry::years().to_vec(), |year| vec![year]);
+    let mut days = Vec::new();
+    for &year in &years {
+        days.extend(select(year, None, true)?);
+    }
+    let cache = Cache::open(args.fresh);
+    let runner = Runner {
+        budget: run::limit(args.timeout),
+        cache: Some(&cache),
+        ..Runner::default()
+    };
+    let report = check(&days, runner, read_archive().as_ref())?;
+    save_cache(&cache);
+    println!("{}", Matrix::new(&years, &report.checks));
+    Ok(())
+}
+
+fn client(base_url: Option<&str>) -> Result<Client> {
+    let client = Client::from_env()?;
+    Ok(match base_url {
+        Some(url) => client.with_base_url(url),
+        None => client,
+    })
+}
+
+fn fetch(args: &FetchArgs) -> Result<()> {
+    let client = client(args.base_url.as_deref())?;
+    let archive = Archive::open()?;
+    let dir = fetch::day_dir(args.year, args.day);
+    if args.puzzle {
+        for path in fetch::puzzle(&client, &archive, args.year, args.day, &dir)? {
+            println!("wrote {}", path.display());
+        }
+        return Ok(());
+    }
+    let fetched = fetch::fetch(
+        &client,
+        &fetch::cache_dir(),
+        &archive,
+        args.year,
+        args.day,
+        &dir,
+    )?;
+    let from = if fetched.cached {
+        " from the cache"
+    } else {
+        ""
+    };
+    println!("wrote {}{from}", fetched.path.display());
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<()> {
+    let answer = match &args.answer {
+        Some(answer) => Answer::from(answer.as_str()),
+        None => compute(args)?,
+    };
+    let client = client(args.base_url.as_deref())?;
+    let verdict = submit::submit(
+        &client,
+        &Archive::open()?,
+        args.year,
+        args.day,
+        args.part,
+        &answer,
+    )?;
+    if verdict.is_wrong() {
+        return Err(miette!("{answer} is {verdict}"));
+    }
+    println!(
+        "{} day {:02} part {}: {answer} is {verdict}",
+        args.year, args.day, args.part
+    );
+    Ok(())
+}
+
+/// The answer to submit, solved on the given or default input.
+fn compute(args: &SubmitArgs) -> Result<Answer> {
+    let (day, part) = (select(args.year, Some(args.day), false)?[0], args.part);
+    let input =
+        input::overridden(args.input.as_deref()).unwrap_or_else(|| input::read(day, part))?;
+    let cache = Cache::open(false);
+    let runner = Runner {
+        cache: Some(&cache),
+        ..Runner::default()
+    };
+    let outcome = runner
+        .solve(day, part, &input.text)
+        .ok_or_else(|| miette!("{day} part {part} is not implemented"))?;
+    cache.save().into_diagnostic()?;
+    let solved = outcome
+        .answer
+        .map_err(|failure| miette!("{day} part {part} failed: {failure}"))?;
+    println!("{day} part {part}: {}", solved.answer);
+    Ok(solved.answer)
+}
+
+fn leaderboard(args: &LeaderboardArgs) -> Result<()> {
+    let json = match (&args.file, args.id) {
+        (Some(file), _) => fs::read_to_string(file)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("failed to read {}", file.display()))?,
+        (None, Some(id)) => fetch::leaderboard(
+            &client(args.base_url.as_deref())?,
+            &fetch::cache_dir(),
+            args.year,
+            id,
+        )?,
+        (None, None) => unreachable!("clap requires an id without a file"),
+    };
+    let leaderboard = Leaderboard::parse(&json)?;
+    match args.day {
+        Some(day) => println!("{}", leaderboard.render_day(day)),
+        None => println!("{}", leaderboard.render()),
+    }
+    Ok(())
+}
+
+fn wait_and_fetch(args: &UnlockArgs, clock: &impl Clock) -> Result<()> {
+    let (year, day, at) = unlock::next(clock.now());
+    if !args.wait {
+        println!(
+            "{year} day {day:02} unlocks in {}",
+            unlock::countdown(at.duration_since(clock.now()))
+        );
+        return Ok(());
+    }
+    // fail on a missing session now rather than at midnight
+    let client = client(args.base_url.as_deref())?;
+    unlock::wait_until(clock, at + unlock::GRACE, |left| {
+        eprint!(
+            "\r{year} day {day:02} unlocks in {}",
+            unlock::countdown(left)
+        );
+    });
+    eprintln!();
+
+    let dir = fetch::day_dir(year, day);
+    if !dir.exists() {
+        let template = registry::root()
+            .join(year.to_string())
+            .join("daily-template");
+        unlock::scaffold(&template, &dir, day)?;
+        println!("created {}", dir.display());
+    }
+    let archive = Archive::open()?;
+    let fetched = fetch::fetch(&client, &fetch::cache_dir(), &archive, year, day, &dir)?;
+    println!("wrote {}", fetched.path.display());
+    for path in fetch::puzzle(&client, &archive, year, day, &dir)? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// The days of `args`, all of the year's puzzles without one.
+fn vault_days(args: &VaultArgs) -> Vec<u8> {
+    args.day.map_or_else(
+        || (1..=registry::puzzles(args.year)).collect(),
+        |day| vec![day],
+    )
+}
+
+fn encrypt(args: &VaultArgs) -> Result<()> {
+    let root = registry::root();
+    let key = Key::find(&vault::path(&root, &args.year.to_string(), "key"))?;
+    for path in vault::encrypt(&key, &root, args.year, &vault_days(args))? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn decrypt(args: &VaultArgs) -> Result<()> {
+    for path in vault::decrypt(&registry::root(), args.year, &vault_days(args))? {
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn export(output: Option<&Path>) -> Result<()> {
+    let json = serde_json::to_string_pretty(&Archive::open()?.export()?).into_diagnostic()?;
+    let Some(path) = output else {
+        println!("{json}");
+        return Ok(());
+    };
+    fs::write(path, json + "\n")
+        .into_diagnostic()
+        .wrap_err_with(|| format!("failed to write {}", path.display()))
+}
+
+/// The archive for commands that can do without it, which carry on after a
+/// warning when it can't be opened, e.g. on a read-only checkout.
+fn read_archive() -> Option<Archive> {
+    Archive::open()
+        .inspect_err(|err| eprintln!("warning: {err}, answers only it records are skipped"))
+        .ok()
+}
+
+/// Saves the answers computed, warning instead of failing the command, as
+/// answers not cached are merely computed again.
+fn save_cache(cache: &Cache) {
+    if let Err(err) = cache.save() {
+        eprintln!("warning: failed to save {}: {err}", Cache::path().display());
+    }
+}
+
+fn check(days: &[&'static Day], runner: Runner, archive: Option<&Archive>) -> Result<Report> {
+    let start = Instant::now();
+    let checks = verify::check_all(days, &Part::ALL, runner, archive)?;
+    Ok(Report {
+        checks,
+        total: start.elapsed(),
+    })
+}
+
+fn select(year: u16, day: Option<u8>, all: bool) -> Result<Vec<&'static Day>> {
+    let days = registry::year(year).ok_or_else(|| miette!("no solutions for {year}"))?;
+    match day {
+        Some(day) => registry::find(year, day)
+            .map(|day| vec![day])
+            .ok_or_else(|| miette!("no solution for {year} day {day:02}")),
+        None if all => Ok(days.iter().collect()),
+        None => Err(miette!(
+            help = "pass `--all` to run the whole year",
+            "which day?"
+        )),
+    }
+}
+
+fn run_part(
+    day: &Day,
+    part: Part,
+    input: Result<&input::Input, &input::InputError>,
+    runner: Runner,
+) {
+    if day.solver(part).is_none() {
+        println!("{day} part {part}: not implemented");
+        return;
+    }
+    let outcome = input.map(|input| runner.solve(day, part, &input.text));
+    match outcome {
+        Ok(None) => println!("{day} part {part}: not implemented"),
+        Ok(Some(run::Outcome {
+            answer: Err(err @ Failure::Timeout(_)),
+            ..
+        })) => println!("{day} part {part}: {err}"),
+        Ok(Some(run::Outcome {
+            answer: Ok(Solved { answer, .. }),
+            elapsed,
+            cached: true,
+        })) => println!("{day} part {part}: {answer} (cached, took {elapsed:.2?})"),
+        Ok(Some(run::Outcome {
+            answer: Ok(Solved { answer, timings }),
+            elapsed,
+            ..
+        })) => match timings {
+            Some(Timings { parse, solve }) => println!(
+                "{day} part {part}: {answer} ({elapsed:.2?}: parse {parse:.2?}, solve {solve:.2?})"
+            ),
+            None => println!("{day} part {part}: {answer} ({elapsed:.2?})"),
+        },
+        Ok(Some(run::Outcome {
+            answer: Err(err),
+            elapsed,
+            ..
+        })) => println!("{day} part {part}: failed after {elapsed:.2?}: {err}"),
+        Err(err) => println!("{day} part {part}: {err}"),
+    }
+}
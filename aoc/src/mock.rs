@@ -0,0 +1,416 @@
+//! A stand-in for adventofcode.com serving fixture files, so the whole
+//! client can be tested on localhost, failures included.
+//!
+//! Fixtures are laid out per year:
+//!
+//! - `<year>/day-NN/input.txt` and `<year>/day-NN/puzzle.html`, the input and
+//!   page of a day;
+//! - `<year>/answers.toml`, the answers submissions are judged against, in
+//!   the format of [`Answers`];
+//! - `<year>/leaderboard/<id>.json`, the private leaderboards.
+//!
+//! Requests need a session cookie, the configured one if any. The [`Mode`]
+//! switches on a failure of the site, at start or with `PUT /mock/mode`.
+
+use crate::{
+    answers::Answers,
+    registry::{Answer, Part},
+    server::Response,
+};
+use clap::ValueEnum;
+use miette::{IntoDiagnostic, Result};
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    fs,
+    net::ToSocketAddrs,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Arc, Mutex, PoisonError},
+    thread,
+};
+use tiny_http::{Header, Request, Server};
+
+/// The fixtures committed with this crate: 2025 day 1 with the example as
+/// input, and a leaderboard.
+#[must_use]
+pub fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures")
+        .join("mock")
+}
+
+/// Path of the endpoint switching the [`Mode`].
+pub const MODE_PATH: &str = "/mock/mode";
+
+/// The wait a rate-limited submission is told about.
+pub const WAIT: &str = "1m 4s";
+
+/// How the stand-in misbehaves.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Mode {
+    /// Serve the fixtures like the site would.
+    #[default]
+    Normal,
+    /// Refuse every session as logged out, `400 Bad Request`.
+    Expired,
+    /// Reply `404 Not Found` to every day, as before it unlocks.
+    Locked,
+    /// Turn every submission away for being too soon after the last one.
+    RateLimited,
+}
+
+impl Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = self.to_possible_value().expect("no mode is skipped");
+        f.write_str(value.get_name())
+    }
+}
+
+impl FromStr for Mode {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <Self as ValueEnum>::from_str(s.trim(), true)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Mock {
+    fixtures: PathBuf,
+    session: Option<String>,
+    mode: Mutex<Mode>,
+    /// Parts answered right so far.
+    solved: Mutex<HashSet<(u16, u8, Part)>>,
+}
+
+fn reply(status: u16, body: impl Into<String>) -> Response {
+    Response {
+        status,
+        body: body.into(),
+    }
+}
+
+/// A page whose article says `text`, like the replies to submissions.
+fn page(text: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><body><main><article><p>{text}</p></article></main></body></html>\n"
+    )
+}
+
+/// Decodes a form value, `+` and `%XX` escapes included.
+fn decode(value: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut chars = value.bytes();
+    while let Some(byte) = chars.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [chars.next(), chars.next()];
+                let decoded = match hex {
+                    [Some(high), Some(low)] => std::str::from_utf8(&[high, low])
+                        .ok()
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+                    _ => None,
+                };
+                bytes.push(decoded.unwrap_or(b'%'));
+            }
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+impl Mock {
+    /// A well-behaved stand-in serving `fixtures` to any session.
+    #[must_use]
+    pub fn new(fixtures: impl Into<PathBuf>) -> Self {
+        Self {
+            fixtures: fixtures.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Only accepts the session cookie `session`.
+    #[must_use]
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode(self, mode: Mode) -> Self {
+        self.set_mode(mode);
+        self
+    }
+
+    pub fn set_mode(&self, mode: Mode) {
+        *self.mode.lock().unwrap_or_else(PoisonError::into_inner) = mode;
+    }
+
+    #[must_use]
+    pub fn mode(&self) -> Mode {
+        *self.mode.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.fixtures
+            .join(year.to_string())
+            .join(format!("day-{day:02}"))
+    }
+
+    fn file(path: &Path) -> Response {
+        fs::read_to_string(path)
+            .map_or_else(|_| reply(404, "404 Not Found"), |body| reply(200, body))
+    }
+
+    /// Answers one request, `cookie` being its `Cookie` header.
+    #[must_use]
+    pub fn handle(&self, method: &str, url: &str, cookie: Option<&str>, body: &str) -> Response {
+        let path = url.split_once('?').map_or(url, |(path, _)| path);
+        if path == MODE_PATH {
+            return match (method, body.parse()) {
+                ("GET", _) => reply(200, self.mode().to_string()),
+                ("PUT" | "POST", Ok(mode)) => {
+                    self.set_mode(mode);
+                    reply(200, mode.to_string())
+                }
+                ("PUT" | "POST", Err(err)) => reply(400, err),
+                _ => reply(405, format!("{method} is not allowed here")),
+            };
+        }
+        let session = cookie
+            .into_iter()
+            .flat_map(|cookie| cookie.split(';'))
+            .find_map(|pair| pair.trim().strip_prefix("session="));
+        let logged_in = session.is_some_and(|session| {
+            !session.is_empty()
+                && self
+                    .session
+                    .as_deref()
+                    .is_none_or(|expected| session == expected)
+        });
+        if !logged_in || self.mode() == Mode::Expired {
+            return reply(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+            );
+        }
+
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+        let (year, day) = match segments.as_slice() {
+            [year, "day", day, ..] => (year.parse::<u16>(), day.parse::<u8>()),
+            [year, "leaderboard", "private", "view", id] => {
+                let (Ok(year), Some(id)) = (year.parse::<u16>(), id.strip_suffix(".json")) else {
+                    return reply(404, "404 Not Found");
+                };
+                return Self::file(
+                    &self
+                        .fixtures
+                        .join(year.to_string())
+                        .join("leaderboard")
+                        .join(format!("{id}.json")),
+                );
+            }
+            _ => return reply(404, "404 Not Found"),
+        };
+        let (Ok(year), Ok(day)) = (year, day) else {
+            return reply(404, "404 Not Found");
+        };
+        if self.mode() == Mode::Locked {
+            return reply(
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time; the link will be enabled on the calendar the instant this puzzle becomes available.\n",
+            );
+        }
+        match (method, &segments[3..]) {
+            ("GET", []) => Self::file(&self.day_dir(year, day).join("puzzle.html")),
+            ("GET", ["input"]) => Self::file(&self.day_dir(year, day).join("input.txt")),
+            ("POST", ["answer"]) => self.judge(year, day, body),
+            _ => reply(404, "404 Not Found"),
+        }
+    }
+
+    /// Whether `part` is the level to solve next, marking it solved when the
+    /// answer is `correct`.
+    fn attempt(&self, year: u16, day: u8, part: Part, correct: bool) -> bool {
+        let mut solved = self.solved.lock().unwrap_or_else(PoisonError::into_inner);
+        let open = !solved.contains(&(year, day, part))
+            && (part == Part::One || solved.contains(&(year, day, Part::One)));
+        if open && correct {
+            solved.insert((year, day, part));
+        }
+        open
+    }
+
+    /// Judges a submission of the `level` and `answer` form fields.
+    fn judge(&self, year: u16, day: u8, form: &str) -> Response {
+        let field = |name: &str| {
+            form.split('&')
+                .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+                .map(decode)
+        };
+        let (Some(Ok(part)), Some(answer)) = (
+            field("level").map(|level| level.parse::<Part>()),
+            field("answer"),
+        ) else {
+            return reply(400, "400 Bad Request");
+        };
+        if self.mode() == Mode::RateLimited {
+            return reply(
+                200,
+                page(&format!(
+                    "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {WAIT} left to wait."
+                )),
+            );
+        }
+        let path = self
+            .fixtures
+            .join(year.to_string())
+            .join(crate::answers::FILE);
+        let expected = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| Answers::parse(&text, &path).ok())
+            .and_then(|answers| answers.get(day, part).cloned());
+        let Some(expected) = expected else {
+            return reply(404, "404 Not Found");
+        };
+        let answer = Answer::from(answer.trim());
+        if !self.attempt(year, day, part, answer == expected) {
+            return reply(
+                200,
+                page("You don't seem to be solving the right level.  Did you already complete it?"),
+            );
+        }
+        if answer == expected {
+            return reply(
+                200,
+                page(
+                    "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.",
+                ),
+            );
+        }
+        let hint = match (&answer, &expected) {
+            (Answer::Int(given), Answer::Int(expected)) if given > expected => {
+                "; your answer is too high"
+            }
+            (Answer::Int(given), Answer::Int(expected)) if given < expected => {
+                "; your answer is too low"
+            }
+            _ => "",
+        };
+        reply(
+            200,
+            page(&format!(
+                "That's not the right answer{hint}.  If you're stuck, make sure you're using the full input data."
+            )),
+        )
+    }
+}
+
+/// Serves `mock` on `addr` until the process is stopped, each request on
+/// its own thread. Calls `ready` with the bound address first.
+///
+/// # Errors
+///
+/// When `addr` can't be bound.
+pub fn serve(addr: impl ToSocketAddrs, mock: Mock, ready: impl FnOnce(&str)) -> Result<()> {
+    let server =
+        Server::http(addr).map_err(|err| miette::miette!("failed to start the server: {err}"))?;
+    ready(&server.server_addr().to_string());
+    let mock = Arc::new(mock);
+    for request in server.incoming_requests() {
+        let mock = Arc::clone(&mock);
+        thread::spawn(move || respond(request, &mock));
+    }
+    Ok(())
+}
+
+fn respond(mut request: Request, mock: &Mock) -> Result<()> {
+    let mut body = String::new();
+    let response = match request.as_reader().read_to_string(&mut body) {
+        Ok(_) => {
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|header| header.field.equiv("Cookie"))
+                .map(|header| header.value.to_string());
+            mock.handle(
+                request.method().as_str(),
+                request.url(),
+                cookie.as_deref(),
+                &body,
+            )
+        }
+        Err(err) => reply(400, format!("body is not UTF-8 text: {err}")),
+    };
+    let kind = if response.body.starts_with('{') {
+        "application/json"
+    } else if response.body.starts_with("<!DOCTYPE") {
+        "text/html; charset=utf-8"
+    } else {
+        "text/plain; charset=utf-8"
+    };
+    let header =
+        Header::from_bytes("Content-Type", kind).expect("the content type is a valid header");
+    request
+        .respond(
+            tiny_http::Response::from_string(response.body)
+                .with_status_code(response.status)
+                .with_header(header),
+        )
+        .into_diagnostic()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SESSION: Option<&str> = Some("session=abc");
+
+    #[test]
+    fn test_handle() {
+        let mock = Mock::new(fixtures()).with_session("abc");
+        let get = |path| mock.handle("GET", path, SESSION, "");
+        let input = get("/2025/day/1/input");
+        assert_eq!(input.status, 200);
+        assert!(input.body.starts_with("L68\n"));
+        assert!(get("/2025/day/1").body.contains("--- Part Two ---"));
+        assert_eq!(get("/2025/day/2/input").status, 404);
+        assert!(
+            get("/2025/leaderboard/private/view/1.json")
+                .body
+                .contains("\"Alice\"")
+        );
+        assert_eq!(
+            mock.handle("GET", "/2025/day/1/input", Some("session=xyz"), "")
+                .status,
+            400
+        );
+        assert_eq!(
+            mock.handle("GET", "/2025/day/1/input", None, "").status,
+            400
+        );
+
+        let submit = |form| {
+            mock.handle("POST", "/2025/day/1/answer", SESSION, form)
+                .body
+        };
+        assert!(submit("level=2&answer=6").contains("solving the right level"));
+        assert!(submit("level=1&answer=4").contains("too high"));
+        assert!(submit("level=1&answer=3").contains("right answer"));
+        assert!(submit("level=1&answer=3").contains("solving the right level"));
+        assert!(submit("level=2&answer=%206").contains("right answer"));
+
+        assert_eq!(mock.handle("PUT", MODE_PATH, None, "locked").status, 200);
+        assert_eq!(mock.mode(), Mode::Locked);
+        assert_eq!(get("/2025/day/1/input").status, 404);
+        assert_eq!(mock.handle("PUT", MODE_PATH, None, "broken").status, 400);
+        mock.set_mode(Mode::Expired);
+        assert_eq!(get("/2025/leaderboard/private/view/1.json").status, 400);
+        mock.set_mode(Mode::RateLimited);
+        assert!(submit("level=1&answer=1").contains("You have 1m 4s left to wait"));
+    }
+}
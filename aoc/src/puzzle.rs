@@ -0,0 +1,188 @@
+//! Reading a puzzle page: its description as Markdown, and the example each
+//! part works through with the answer it expects.
+
+use crate::registry::Answer;
+use scraper::{ElementRef, Html, Node, Selector, node::Element};
+use std::fmt::Write;
+
+/// One part's description, a page has a second one once part 1 is solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Article {
+    pub markdown: String,
+    /// The first `<pre><code>` block, usually the example input.
+    pub example: Option<String>,
+    /// The last emphasized code, usually what the example should give.
+    pub answer: Option<Answer>,
+}
+
+/// The `<article class="day-desc">`s of a puzzle page, part 1 first.
+#[must_use]
+pub fn parse(html: &str) -> Vec<Article> {
+    let page = Html::parse_document(html);
+    let (articles, blocks, answers) = (
+        select("article.day-desc"),
+        select("pre > code"),
+        select("code > em, em > code"),
+    );
+    page.select(&articles)
+        .map(|article| Article {
+            markdown: markdown(article),
+            example: article.select(&blocks).next().map(|code| {
+                let mut text = code.text().collect::<String>();
+                if !text.ends_with('\n') {
+                    text.push('\n');
+                }
+                text
+            }),
+            answer: article
+                .select(&answers)
+                .last()
+                .map(|answer| Answer::from(answer.text().collect::<String>().trim())),
+        })
+        .collect()
+}
+
+fn select(selector: &str) -> Selector {
+    Selector::parse(selector).expect("selectors are valid")
+}
+
+/// The whole puzzle as one Markdown document.
+#[must_use]
+pub fn document(articles: &[Article]) -> String {
+    articles
+        .iter()
+        .map(|article| article.markdown.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        + "\n"
+}
+
+fn markdown(article: ElementRef) -> String {
+    let mut out = String::new();
+    for child in article.children().filter_map(ElementRef::wrap) {
+        let element = child.value();
+        match element.name() {
+            "h2" => {
+                let _ = write!(out, "## {}\n\n", inline(child).trim());
+            }
+            "pre" => {
+                let _ = write!(out, "```\n{}", child.text().collect::<String>());
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            "ul" | "ol" => {
+                for (i, item) in child
+                    .children()
+                    .filter_map(ElementRef::wrap)
+                    .filter(|item| item.value().name() == "li")
+                    .enumerate()
+                {
+                    let marker = if element.name() == "ol" {
+                        format!("{}.", i + 1)
+                    } else {
+                        "-".to_string()
+                    };
+                    let _ = writeln!(out, "{marker} {}", inline(item).trim());
+                }
+                out.push('\n');
+            }
+            _ => {
+                let _ = write!(out, "{}\n\n", inline(child).trim());
+            }
+        }
+    }
+    out
+}
+
+/// Markdown of the inline content of `element`.
+fn inline(element: ElementRef) -> String {
+    let mut out = String::new();
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(text),
+            Node::Element(inner) => {
+                let inner_ref = ElementRef::wrap(child).expect("the node is an element");
+                out.push_str(&styled(inner, inner_ref));
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+fn styled(element: &Element, node: ElementRef) -> String {
+    match element.name() {
+        "code" => {
+            let code = format!("`{}`", node.text().collect::<String>());
+            let emphasized = node
+                .descendants()
+                .filter_map(ElementRef::wrap)
+                .any(|inner| inner.value().name() == "em");
+            if emphasized {
+                format!("**{code}**")
+            } else {
+                code
+            }
+        }
+        "em" => {
+            let text = inline(node);
+            if text.starts_with("**") || text.trim().is_empty() {
+                text
+            } else {
+                format!("**{text}**")
+            }
+        }
+        "a" => element.attr("href").map_or_else(
+            || inline(node),
+            |href| format!("[{}]({href})", inline(node)),
+        ),
+        "br" => "  \n".to_string(),
+        _ => inline(node),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2>
+<p>Something is <em>wrong</em> with <a href="/2023/about">global snow production</a>.</p>
+<p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+</code></pre>
+<ul><li>In <code>1abc2</code>, the value is <code>12</code>.</li></ul>
+<p>Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>54331</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>two1nine
+</code></pre>
+<p>Adding these together produces <em><code>281</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_parse() {
+        let articles = parse(PAGE);
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].example.as_deref(), Some("1abc2\npqr3stu8vwx\n"));
+        assert_eq!(articles[0].answer, Some(Answer::Int(142)));
+        assert_eq!(articles[1].example.as_deref(), Some("two1nine\n"));
+        assert_eq!(articles[1].answer, Some(Answer::Int(281)));
+
+        let markdown = document(&articles);
+        assert!(markdown.starts_with("## --- Day 1: Trebuchet?! ---\n\n"));
+        assert!(
+            markdown.contains("Something is **wrong** with [global snow production](/2023/about).")
+        );
+        assert!(markdown.contains("```\n1abc2\npqr3stu8vwx\n```"));
+        assert!(markdown.contains("- In `1abc2`, the value is `12`."));
+        assert!(markdown.contains("produces **`142`**."));
+        assert!(markdown.contains("produces **`281`**."));
+        assert!(!markdown.contains("54331"));
+    }
+}
@@ -0,0 +1,258 @@
+//! Every solution of every year, addressable by year, day and part.
+
+/// Wraps a `partN::process` function into a [`Solver`], whatever answer and
+/// error type the year it comes from settled on. Days with
+/// [`aoc_core::Params`] name their type second, the preset of the run picks
+/// its values.
+macro_rules! solver {
+    ($process:expr) => {
+        Some(|input: &str, _: $crate::registry::Preset| {
+            ($process)(input)
+                .map(|answer| $crate::registry::Solved {
+                    answer: answer.into(),
+                    timings: None,
+                })
+                .map_err($crate::registry::IntoReport::into_report)
+        })
+    };
+    ($process:expr, $params:ty) => {
+        Some(|input: &str, preset: $crate::registry::Preset| {
+            ($process)(input, <$params as aoc_core::Params>::preset(preset))
+                .map(|answer| $crate::registry::Solved {
+                    answer: answer.into(),
+                    timings: None,
+                })
+                .map_err($crate::registry::IntoReport::into_report)
+        })
+    };
+}
+
+/// Registers a day whose crate exposes the usual `part1` and `part2` modules.
+macro_rules! day {
+    ($year:literal, $day:literal, $krate:ident) => {
+        Day {
+            year: $year,
+            day: $day,
+            part1: solver!($krate::part1::process),
+            part2: solver!($krate::part2::process),
+        }
+    };
+}
+
+/// Registers a day implementing [`Solution`], so parsing is timed apart from
+/// solving.
+macro_rules! solution {
+    ($year:literal, $day:literal, $solution:ty) => {
+        Day {
+            year: $year,
+            day: $day,
+            part1: Some(|input: &str, _: $crate::registry::Preset| {
+                $crate::registry::split::<$solution>($crate::registry::Part::One, input)
+            }),
+            part2: Some(|input: &str, _: $crate::registry::Preset| {
+                $crate::registry::split::<$solution>($crate::registry::Part::Two, input)
+            }),
+        }
+    };
+}
+
+mod y2023;
+mod y2024;
+mod y2025;
+
+pub use aoc_core::{Answer, Part, Preset};
+
+use aoc_core::{Solution, solution::Timings};
+use miette::{Report, Result};
+use std::{fmt::Display, path::PathBuf};
+
+/// A puzzle part behind a uniform signature: input and the [`Preset`] of any
+/// parameters in, rendered answer out.
+pub type Solver = fn(&str, Preset) -> Result<Solved>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    pub answer: Answer,
+    /// Parse and solve phases, for days implementing [`Solution`].
+    pub timings: Option<Timings>,
+}
+
+/// An error a solver fails with, turned into a [`Report`] that keeps its
+/// causes and help instead of only its message.
+pub trait IntoReport {
+    fn into_report(self) -> Report;
+}
+
+impl IntoReport for Report {
+    fn into_report(self) -> Report {
+        self
+    }
+}
+
+/// The 2023 solutions fail with [`eyre::Report`].
+impl IntoReport for eyre::Report {
+    fn into_report(self) -> Report {
+        let error: Box<dyn std::error::Error + Send + Sync> = self.into();
+        Report::new_boxed(error.into())
+    }
+}
+
+fn split<S: Solution>(part: Part, input: &str) -> Result<Solved> {
+    let (answer, timings) = aoc_core::solution::timed::<S>(part, input)?;
+    Ok(Solved {
+        answer: answer.into(),
+        timings: Some(timings),
+    })
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub part1: Option<Solver>,
+    pub part2: Option<Solver>,
+}
+
+impl Day {
+    #[must_use]
+    pub const fn solver(&self, part: Part) -> Option<Solver> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+
+    /// Directory of the day's crate, e.g. `2025/day-10`.
+    #[must_use]
+    pub fn dir(&self) -> PathBuf {
+        root()
+            .join(self.year.to_string())
+            .join(format!("day-{:02}", self.day))
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} day {:02}", self.year, self.day)
+    }
+}
+
+/// Root of the repository, where the yearly workspaces live.
+#[must_use]
+#[allow(clippy::missing_panics_doc)]
+pub fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("`aoc` lives inside the repository")
+        .to_path_buf()
+}
+
+#[must_use]
+pub const fn years() -> [u16; 3] {
+    [2023, 2024, 2025]
+}
+
+/// Number of puzzles of `year`, 25 a year until 2025 cut it down to 12.
+#[must_use]
+pub const fn puzzles(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+/// Every registered day of `year`, in order.
+#[must_use]
+pub const fn year(year: u16) -> Option<&'static [Day]> {
+    match year {
+        2023 => Some(y2023::DAYS),
+        2024 => Some(y2024::DAYS),
+        2025 => Some(y2025::DAYS),
+        _ => None,
+    }
+}
+
+/// Parses a day given as `1`, `01` or, like the justfiles name them,
+/// `day-01`.
+///
+/// # Errors
+///
+/// When `text` is none of these.
+pub fn parse_day(text: &str) -> Result<u8, String> {
+    text.strip_prefix("day-")
+        .unwrap_or(text)
+        .parse()
+        .map_err(|_| format!("`{text}` is not a day, expected e.g. `1` or `day-01`"))
+}
+
+pub fn all() -> impl Iterator<Item = &'static Day> {
+    years().into_iter().filter_map(year).flatten()
+}
+
+#[must_use]
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    self::year(year)?.iter().find(|entry| entry.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_registry_is_ordered_and_unique() {
+        for year in years() {
+            let days = self::year(year).unwrap();
+            assert!(days.iter().all(|day| day.year == year));
+            assert!(days.windows(2).all(|pair| pair[0].day < pair[1].day));
+        }
+        let unique = all().map(|day| (day.year, day.day)).collect::<HashSet<_>>();
+        assert_eq!(unique.len(), all().count());
+    }
+
+    #[test]
+    fn test_solvers() -> Result<()> {
+        let day = find(2025, 1).unwrap();
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        assert_eq!(
+            day.solver(Part::One).unwrap()(input, Preset::Real)?.answer,
+            Answer::Int(3)
+        );
+        assert_eq!(
+            day.solver(Part::Two).unwrap()(input, Preset::Real)?.answer,
+            Answer::Int(6)
+        );
+        assert!(find(2024, 3).unwrap().solver(Part::Two).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_errors_keep_their_causes() {
+        let eyre = eyre::eyre!("bad digit").wrap_err("line 1");
+        let report = eyre.into_report();
+        assert_eq!(report.to_string(), "line 1");
+        assert_eq!(
+            report.chain().map(ToString::to_string).collect::<Vec<_>>(),
+            ["line 1", "bad digit"]
+        );
+    }
+
+    #[test]
+    fn test_preset_solvers() -> Result<()> {
+        let input = "162,817,812\n57,618,57\n906,360,560\n592,479,940\n352,342,300\n466,668,158\n542,29,236\n431,825,988\n739,650,466\n52,470,668\n216,146,977\n819,987,18\n117,168,530\n805,96,715\n346,949,466\n970,615,88\n941,993,340\n862,61,35\n984,92,344\n425,690,689\n";
+        let part1 = find(2025, 8).unwrap().solver(Part::One).unwrap();
+        assert_eq!(part1(input, Preset::Example)?.answer, Answer::Int(40));
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_solvers() -> Result<()> {
+        let day = find(2025, 5).unwrap();
+        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n";
+        let solved = day.solver(Part::One).unwrap()(input, Preset::Real)?;
+        assert_eq!(solved.answer, Answer::Int(3));
+        assert!(solved.timings.is_some());
+        assert_eq!(
+            day.solver(Part::Two).unwrap()(input, Preset::Real)?.answer,
+            Answer::Int(14)
+        );
+        Ok(())
+    }
+}
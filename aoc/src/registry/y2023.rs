@@ -0,0 +1,18 @@
+use super::Day;
+
+pub const DAYS: &[Day] = &[
+    day!(2023, 1, y2023_day_01),
+    day!(2023, 2, y2023_day_02),
+    day!(2023, 3, y2023_day_03),
+    day!(2023, 4, y2023_day_04),
+    day!(2023, 5, y2023_day_05),
+    day!(2023, 6, y2023_day_06),
+    day!(2023, 7, y2023_day_07),
+    day!(2023, 8, y2023_day_08),
+    day!(2023, 9, y2023_day_09),
+    day!(2023, 11, y2023_day_11),
+    day!(2023, 12, y2023_day_12),
+    day!(2023, 13, y2023_day_13),
+    day!(2023, 14, y2023_day_14),
+    day!(2023, 15, y2023_day_15),
+];
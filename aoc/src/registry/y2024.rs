@@ -0,0 +1,17 @@
+use super::Day;
+
+pub const DAYS: &[Day] = &[
+    day!(2024, 1, y2024_day_01),
+    day!(2024, 2, y2024_day_02),
+    Day {
+        year: 2024,
+        day: 3,
+        part1: solver!(y2024_day_03::part1::process),
+        part2: None,
+    },
+    day!(2024, 4, y2024_day_04),
+    solution!(2024, 5, y2024_day_05::Puzzle),
+    day!(2024, 6, y2024_day_06),
+    day!(2024, 7, y2024_day_07),
+    day!(2024, 8, y2024_day_08),
+];
@@ -0,0 +1,21 @@
+use super::Day;
+
+pub const DAYS: &[Day] = &[
+    day!(2025, 1, y2025_day_01),
+    day!(2025, 2, y2025_day_02),
+    day!(2025, 3, y2025_day_03),
+    day!(2025, 4, y2025_day_04),
+    solution!(2025, 5, y2025_day_05::Puzzle),
+    day!(2025, 6, y2025_day_06),
+    day!(2025, 7, y2025_day_07),
+    Day {
+        year: 2025,
+        day: 8,
+        // the real input connects the 1000 closest pairs
+        part1: solver!(y2025_day_08::part1::process, y2025_day_08::Params),
+        part2: solver!(y2025_day_08::part2::process),
+    },
+    day!(2025, 9, y2025_day_09),
+    day!(2025, 10, y2025_day_10),
+    day!(2025, 11, y2025_day_11),
+];
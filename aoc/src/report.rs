@@ -0,0 +1,350 @@
+//! Rendering the [`Check`]s of a run for people, dashboards and CI.
+
+use crate::verify::{Check, Status};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::{fmt::Write, time::Duration};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// An aligned table with a summary
+    #[default]
+    Pretty,
+    Json,
+    Csv,
+    /// JUnit XML, one test case per part
+    #[allow(clippy::doc_markdown)]
+    Junit,
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub checks: Vec<Check>,
+    /// Wall-clock time of the whole run, less than the sum of the parts when
+    /// they ran in parallel.
+    pub total: Duration,
+}
+
+#[derive(Debug, Serialize)]
+struct Row {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: &'static str,
+    answer: Option<String>,
+    detail: Option<String>,
+    seconds: Option<f64>,
+    cached: bool,
+}
+
+impl From<&Check> for Row {
+    fn from(check: &Check) -> Self {
+        Self {
+            year: check.day.year,
+            day: check.day.day,
+            part: check.part.number(),
+            status: check.status.label(),
+            answer: check.answer.as_ref().map(ToString::to_string),
+            detail: check.status.detail(),
+            seconds: check.elapsed.map(|elapsed| elapsed.as_secs_f64()),
+            cached: check.cached,
+        }
+    }
+}
+
+impl Report {
+    #[must_use]
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Pretty => self.pretty(),
+            Format::Json => self.json(),
+            Format::Csv => self.csv(),
+            Format::Junit => self.junit(),
+        }
+    }
+
+    fn pretty(&self) -> String {
+        let rows = self
+            .checks
+            .iter()
+            .map(|check| {
+                [
+                    check.day.to_string(),
+                    check.part.to_string(),
+                    check.status.to_string(),
+                    check
+                        .answer
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                    check
+                        .elapsed
+                        .map(|elapsed| {
+                            if check.cached {
+                                format!("{elapsed:.2?} (cached)")
+                            } else {
+                                format!("{elapsed:.2?}")
+                            }
+                        })
+                        .unwrap_or_default(),
+                    check.status.detail().unwrap_or_default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let mut out = table(&["day", "part", "status", "answer", "time", ""], &rows);
+        let count = |label| self.count(label);
+        let _ = write!(
+            out,
+            "{} passed, {} mismatched, {} failed, {} unimplemented, {} unrecorded, {} without input in {:.2?}",
+            count("pass"),
+            count("mismatch"),
+            count("fail"),
+            count("unimplemented"),
+            count("unrecorded"),
+            count("no input"),
+            self.total,
+        );
+        out
+    }
+
+    fn json(&self) -> String {
+        #[derive(Serialize)]
+        struct Json {
+            seconds: f64,
+            parts: Vec<Row>,
+        }
+        let json = Json {
+            seconds: self.total.as_secs_f64(),
+            parts: self.checks.iter().map(Row::from).collect(),
+        };
+        serde_json::to_string_pretty(&json).expect("rows serialize to JSON")
+    }
+
+    fn csv(&self) -> String {
+        let mut out = "year,day,part,status,answer,seconds,detail\n".to_string();
+        for row in self.checks.iter().map(Row::from) {
+            let fields = [
+                row.year.to_string(),
+                row.day.to_string(),
+                row.part.to_string(),
+                row.status.to_string(),
+                row.answer.unwrap_or_default(),
+                row.seconds.map(|s| s.to_string()).unwrap_or_default(),
+                row.detail.unwrap_or_default(),
+            ];
+            let fields = fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>();
+            out.push_str(&fields.join(","));
+            out.push('\n');
+        }
+        out
+    }
+
+    fn junit(&self) -> String {
+        let mut years = self
+            .checks
+            .iter()
+            .map(|check| check.day.year)
+            .collect::<Vec<_>>();
+        years.dedup();
+
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            out,
+            "<testsuites name=\"aoc\" tests=\"{}\" time=\"{:.3}\">",
+            self.checks.len(),
+            self.total.as_secs_f64()
+        );
+        for year in years {
+            let checks = self
+                .checks
+                .iter()
+                .filter(|check| check.day.year == year)
+                .collect::<Vec<_>>();
+            let count = |f: fn(&Status) -> bool| checks.iter().filter(|c| f(&c.status)).count();
+            let _ = writeln!(
+                out,
+                "  <testsuite name=\"{year}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+                checks.len(),
+                count(|status| matches!(status, Status::Mismatch { .. })),
+                count(|status| matches!(status, Status::Fail(_))),
+                count(|status| matches!(status, Status::Unimplemented | Status::NoInput)),
+                checks
+                    .iter()
+                    .filter_map(|check| check.elapsed)
+                    .sum::<Duration>()
+                    .as_secs_f64(),
+            );
+            for check in checks {
+                let _ = write!(
+                    out,
+                    "    <testcase classname=\"{}.day-{:02}\" name=\"part {}\" time=\"{:.3}\"",
+                    check.day.year,
+                    check.day.day,
+                    check.part,
+                    check.elapsed.unwrap_or_default().as_secs_f64(),
+                );
+                let detail = xml_escape(&check.status.detail().unwrap_or_default());
+                match &check.status {
+                    Status::Pass | Status::Unrecorded => out.push_str("/>\n"),
+                    Status::Mismatch { .. } => {
+                        let _ = writeln!(
+                            out,
+                            ">\n      <failure message=\"{detail}\"/>\n    </testcase>"
+                        );
+                    }
+                    Status::Fail(_) => {
+                        let _ = writeln!(
+                            out,
+                            ">\n      <error message=\"{detail}\"/>\n    </testcase>"
+                        );
+                    }
+                    Status::Unimplemented | Status::NoInput => {
+                        let _ = writeln!(
+                            out,
+                            ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                            check.status
+                        );
+                    }
+                }
+            }
+            out.push_str("  </testsuite>\n");
+        }
+        out.push_str("</testsuites>");
+        out
+    }
+
+    fn count(&self, label: &str) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.status.label() == label)
+            .count()
+    }
+}
+
+/// Left-aligns `rows` under `header`, one line per row.
+pub(crate) fn table<const N: usize>(header: &[&str; N], rows: &[[String; N]]) -> String {
+    let widths = rows.iter().fold(header.map(str::len), |mut widths, row| {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+        widths
+    });
+    let line = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut out = line(&header.map(String::from));
+    out.push('\n');
+    for row in rows {
+        out.push_str(&line(row));
+        out.push('\n');
+    }
+    out
+}
+
+pub(crate) fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+pub(crate) fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{self, Answer, Part};
+
+    fn report() -> Report {
+        let day = registry::find(2025, 1).unwrap();
+        Report {
+            checks: vec![
+                Check {
+                    day,
+                    part: Part::One,
+                    status: Status::Pass,
+                    answer: Some(Answer::Int(1150)),
+                    elapsed: Some(Duration::from_millis(2)),
+                    cached: true,
+                },
+                Check {
+                    day,
+                    part: Part::Two,
+                    status: Status::Mismatch {
+                        expected: Answer::Int(6738),
+                    },
+                    answer: Some(Answer::Int(6737)),
+                    elapsed: None,
+                    cached: false,
+                },
+            ],
+            total: Duration::from_millis(3),
+        }
+    }
+
+    #[test]
+    fn test_pretty() {
+        assert_eq!(
+            report().render(Format::Pretty),
+            "day          part  status    answer  time
+2025 day 01  1     pass      1150    2.00ms (cached)
+2025 day 01  2     mismatch  6737                     expected 6738
+1 passed, 1 mismatched, 0 failed, 0 unimplemented, 0 unrecorded, 0 without input in 3.00ms"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let json =
+            serde_json::from_str::<serde_json::Value>(&report().render(Format::Json)).unwrap();
+        assert_eq!(json["seconds"], 0.003);
+        assert_eq!(json["parts"][0]["status"], "pass");
+        assert_eq!(json["parts"][0]["answer"], "1150");
+        assert_eq!(json["parts"][0]["cached"], true);
+        assert_eq!(json["parts"][1]["detail"], "expected 6738");
+        assert!(json["parts"][1]["seconds"].is_null());
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            report().render(Format::Csv),
+            "year,day,part,status,answer,seconds,detail
+2025,1,1,pass,1150,0.002,
+2025,1,2,mismatch,6737,,expected 6738
+"
+        );
+        assert_eq!(csv_field("a, \"b\""), "\"a, \"\"b\"\"\"");
+    }
+
+    #[test]
+    fn test_junit() {
+        let xml = report().render(Format::Junit);
+        assert!(xml.contains(
+            "<testsuite name=\"2025\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\""
+        ));
+        assert!(
+            xml.contains("<testcase classname=\"2025.day-01\" name=\"part 1\" time=\"0.002\"/>")
+        );
+        assert!(xml.contains("<failure message=\"expected 6738\"/>"));
+        assert!(xml.ends_with("</testsuites>"));
+    }
+}
@@ -0,0 +1,210 @@
+//! Executing solvers and timing them.
+
+use crate::{
+    cache::Cache,
+    registry::{Day, Part, Preset, Solved, Solver},
+    worker,
+};
+use std::{
+    cell::Cell,
+    fmt::Display,
+    panic,
+    sync::Once,
+    time::{Duration, Instant},
+};
+
+/// Why a solver produced no answer.
+#[derive(Debug)]
+pub enum Failure {
+    Error(miette::Report),
+    Panic(String),
+    /// Still running when its budget ran out.
+    Timeout(Duration),
+    /// The worker process died without reporting, e.g. on a stack overflow.
+    Crash(String),
+}
+
+impl Failure {
+    /// Whether the solver hit a `todo!()`/`unimplemented!()`.
+    #[must_use]
+    pub fn is_unimplemented(&self) -> bool {
+        matches!(self, Self::Panic(msg) if msg.starts_with("not yet implemented") || msg.starts_with("not implemented"))
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error(err) => write!(f, "{err}"),
+            Self::Panic(msg) => write!(f, "panicked: {msg}"),
+            Self::Timeout(budget) => write!(f, "timeout after {budget:?}"),
+            Self::Crash(reason) => write!(f, "crashed: {reason}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Outcome {
+    pub answer: Result<Solved, Failure>,
+    pub elapsed: Duration,
+    /// Taken from the [`Cache`]; `elapsed` is then how long it took back then.
+    pub cached: bool,
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Silences the panic hook on threads currently inside [`run`], which
+/// reports the panic itself. Installed once since parts may run in parallel.
+fn quiet_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                default(info);
+            }
+        }));
+    });
+}
+
+/// Runs `solver`, turning a panic into a [`Failure`] instead of unwinding
+/// through the caller.
+pub fn run(solver: Solver, input: &str, preset: Preset) -> Outcome {
+    quiet_panics();
+    QUIET.set(true);
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| solver(input, preset));
+    let elapsed = start.elapsed();
+    QUIET.set(false);
+
+    let answer = match result {
+        Ok(answer) => answer.map_err(Failure::Error),
+        Err(payload) => Err(Failure::Panic(
+            payload
+                .downcast_ref::<&str>()
+                .map(ToString::to_string)
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string()),
+        )),
+    };
+    Outcome {
+        answer,
+        elapsed,
+        cached: false,
+    }
+}
+
+/// Budget of each part when running many days at once.
+pub const DEFAULT_BUDGET: Duration = Duration::from_secs(10);
+
+/// How parts get run.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Runner<'a> {
+    /// Run each part in a [`worker`] process that is killed once this is
+    /// exceeded.
+    pub budget: Option<Duration>,
+    /// Reuse and record answers. Only answers of the real preset are cached.
+    pub cache: Option<&'a Cache>,
+    /// Parameters of the days that have any.
+    pub preset: Preset,
+}
+
+impl Runner<'_> {
+    /// Runs `part` of `day` if it is implemented.
+    #[must_use]
+    pub fn solve(&self, day: &Day, part: Part, input: &str) -> Option<Outcome> {
+        let solver = day.solver(part)?;
+        let cache = self.cache.filter(|_| self.preset == Preset::Real);
+        if let Some(entry) = cache.and_then(|cache| cache.get(day, part, input)) {
+            return Some(Outcome {
+                answer: Ok(Solved {
+                    answer: entry.answer,
+                    timings: None,
+                }),
+                elapsed: entry.elapsed,
+                cached: true,
+            });
+        }
+
+        let outcome = self.budget.map_or_else(
+            || run(solver, input, self.preset),
+            |budget| worker::run(day, part, input, self.preset, budget),
+        );
+        if let (Some(cache), Ok(solved)) = (cache, &outcome.answer) {
+            cache.put(day, part, input, &solved.answer, outcome.elapsed);
+        }
+        Some(outcome)
+    }
+}
+
+/// Parses a budget such as `10s`, `500ms`, `2m` or a bare number of seconds.
+/// A zero budget means no limit at all, see [`limit`].
+///
+/// # Errors
+///
+/// When `s` isn't one of the above.
+pub fn parse_budget(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value = value
+        .parse::<f64>()
+        .map_err(|_| format!("`{s}` is not a duration like `10s` or `500ms`"))?;
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        other => return Err(format!("unknown unit `{other}`, use `ms`, `s` or `m`")),
+    };
+    Duration::try_from_secs_f64(seconds).map_err(|err| err.to_string())
+}
+
+/// The budget a parsed `timeout` stands for: none when it is zero.
+#[must_use]
+pub const fn limit(timeout: Duration) -> Option<Duration> {
+    if timeout.is_zero() {
+        None
+    } else {
+        Some(timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_catches_panics() {
+        let outcome = run(|_, _| todo!("day xx - part 1"), "", Preset::Real);
+        let failure = outcome.answer.unwrap_err();
+        assert!(failure.is_unimplemented());
+        assert_eq!(
+            failure.to_string(),
+            "panicked: not yet implemented: day xx - part 1"
+        );
+
+        let outcome = run(
+            |input, _| Err(miette::miette!("bad input `{input}`")),
+            "x",
+            Preset::Real,
+        );
+        let failure = outcome.answer.unwrap_err();
+        assert!(!failure.is_unimplemented());
+        assert_eq!(failure.to_string(), "bad input `x`");
+    }
+
+    #[test]
+    fn test_parse_budget() {
+        assert_eq!(parse_budget("10s"), Ok(Duration::from_secs(10)));
+        assert_eq!(parse_budget("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_budget("2m"), Ok(Duration::from_mins(2)));
+        assert_eq!(parse_budget("3"), Ok(Duration::from_secs(3)));
+        assert_eq!(parse_budget("0").map(limit), Ok(None));
+        assert!(parse_budget("10h").is_err());
+        assert!(parse_budget("soon").is_err());
+    }
+}
@@ -0,0 +1,255 @@
+//! Every solver behind a small HTTP API, for tools that would rather not
+//! shell out to cargo.
+//!
+//! - `POST /<year>/<day>/<part>` with the input as body solves that part;
+//!   `?preset=example` picks the parameters of days that have any.
+//! - `GET /` lists the registered days and their parts.
+//!
+//! Replies are JSON. A part that fails still replies with a [`Solution`],
+//! its `error` saying what went wrong.
+
+use crate::{
+    registry::{self, Part, Preset, Solved},
+    run::{Failure, Runner},
+};
+use miette::{IntoDiagnostic, Result};
+use serde::Serialize;
+use std::{net::ToSocketAddrs, thread};
+use tiny_http::{Header, Request, Server};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: &impl Serialize) -> Self {
+        Self {
+            status,
+            body: serde_json::to_string(body).expect("replies serialize to JSON"),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        #[derive(Serialize)]
+        struct Error {
+            error: String,
+        }
+        Self::json(
+            status,
+            &Error {
+                error: message.into(),
+            },
+        )
+    }
+}
+
+/// The reply to solving a part.
+#[derive(Debug, Serialize)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub preset: Preset,
+    pub answer: Option<String>,
+    pub seconds: f64,
+    /// Parse and solve seconds, for days that time them apart.
+    pub timings: Option<Timings>,
+    pub error: Option<Diagnostic>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Timings {
+    pub parse: f64,
+    pub solve: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Diagnostic {
+    /// `error`, `panic`, `unimplemented`, `timeout` or `crash`.
+    pub kind: &'static str,
+    pub message: String,
+}
+
+impl From<&Failure> for Diagnostic {
+    fn from(failure: &Failure) -> Self {
+        let kind = match failure {
+            _ if failure.is_unimplemented() => "unimplemented",
+            Failure::Error(_) => "error",
+            Failure::Panic(_) => "panic",
+            Failure::Timeout(_) => "timeout",
+            Failure::Crash(_) => "crash",
+        };
+        Self {
+            kind,
+            message: failure.to_string(),
+        }
+    }
+}
+
+/// Answers one request.
+#[must_use]
+pub fn handle(method: &str, url: &str, input: &str, runner: Runner) -> Response {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    match (method, segments.as_slice()) {
+        ("GET", []) => list(),
+        ("POST", [year, day, part]) => {
+            let preset = query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix("preset="))
+                .map_or(Ok(Preset::Real), str::parse);
+            match (year.parse(), day.parse(), part.parse(), preset) {
+                (Ok(year), Ok(day), Ok(part), Ok(preset)) => {
+                    solve(Runner { preset, ..runner }, year, day, part, input)
+                }
+                (.., Err(err)) => Response::error(400, err),
+                _ => Response::error(404, format!("no such part: {path}")),
+            }
+        }
+        (_, [] | [_, _, _]) => Response::error(405, format!("{method} is not allowed here")),
+        _ => Response::error(404, format!("no such route: {path}")),
+    }
+}
+
+fn list() -> Response {
+    #[derive(Serialize)]
+    struct Listed {
+        year: u16,
+        day: u8,
+        parts: Vec<u8>,
+    }
+    let days = registry::all()
+        .map(|day| Listed {
+            year: day.year,
+            day: day.day,
+            parts: Part::ALL
+                .into_iter()
+                .filter(|&part| day.solver(part).is_some())
+                .map(Part::number)
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    Response::json(200, &days)
+}
+
+fn solve(runner: Runner, year: u16, day: u8, part: Part, input: &str) -> Response {
+    let Some(found) = registry::find(year, day) else {
+        return Response::error(404, format!("no solution for {year} day {day:02}"));
+    };
+    let Some(outcome) = runner.solve(found, part, input) else {
+        return Response::error(
+            404,
+            format!("{found} has no part {part}, it is not implemented"),
+        );
+    };
+    let mut solution = Solution {
+        year,
+        day,
+        part: part.number(),
+        preset: runner.preset,
+        answer: None,
+        seconds: outcome.elapsed.as_secs_f64(),
+        timings: None,
+        error: None,
+    };
+    let status = match &outcome.answer {
+        Ok(Solved { answer, timings }) => {
+            solution.answer = Some(answer.to_string());
+            solution.timings = timings.map(|timings| Timings {
+                parse: timings.parse.as_secs_f64(),
+                solve: timings.solve.as_secs_f64(),
+            });
+            200
+        }
+        Err(failure) => {
+            solution.error = Some(Diagnostic::from(failure));
+            if failure.is_unimplemented() { 501 } else { 422 }
+        }
+    };
+    Response::json(status, &solution)
+}
+
+/// Serves requests on `addr` until the process is stopped, each on its own
+/// thread. Calls `ready` with the bound address first, useful when `addr`
+/// asks for any free port.
+///
+/// # Errors
+///
+/// When `addr` can't be bound.
+pub fn serve(
+    addr: impl ToSocketAddrs,
+    runner: Runner<'static>,
+    ready: impl FnOnce(&str),
+) -> Result<()> {
+    let server =
+        Server::http(addr).map_err(|err| miette::miette!("failed to start the server: {err}"))?;
+    ready(&server.server_addr().to_string());
+    for request in server.incoming_requests() {
+        thread::spawn(move || respond(request, runner));
+    }
+    Ok(())
+}
+
+fn respond(mut request: Request, runner: Runner) -> Result<()> {
+    let mut input = String::new();
+    let response = match request.as_reader().read_to_string(&mut input) {
+        Ok(_) => handle(request.method().as_str(), request.url(), &input, runner),
+        Err(err) => Response::error(400, format!("body is not UTF-8 text: {err}")),
+    };
+    let header = Header::from_bytes("Content-Type", "application/json")
+        .expect("the content type is a valid header");
+    request
+        .respond(
+            tiny_http::Response::from_string(response.body)
+                .with_status_code(response.status)
+                .with_header(header),
+        )
+        .into_diagnostic()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle() {
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+        let response = handle("POST", "/2025/1/2", input, Runner::default());
+        assert_eq!(response.status, 200);
+        let json = serde_json::from_str::<serde_json::Value>(&response.body).unwrap();
+        assert_eq!(json["answer"], "6");
+        assert_eq!(json["preset"], "real");
+        assert!(json["error"].is_null());
+
+        let response = handle("POST", "/2025/9/2", "", Runner::default());
+        assert_eq!(response.status, 501);
+        assert!(response.body.contains("\"kind\":\"unimplemented\""));
+
+        assert_eq!(
+            handle("POST", "/2024/3/2", "", Runner::default()).status,
+            404
+        );
+        assert_eq!(
+            handle("POST", "/2025/1/3", "", Runner::default()).status,
+            404
+        );
+        assert_eq!(
+            handle("GET", "/2025/1/1", "", Runner::default()).status,
+            405
+        );
+        assert_eq!(
+            handle("POST", "/2025/8/1?preset=huge", "", Runner::default()).status,
+            400
+        );
+        assert!(
+            handle("GET", "/", "", Runner::default())
+                .body
+                .contains("\"year\":2023")
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Where the gaps are: every part of every puzzle of a year, solved or not.
+
+use crate::{
+    registry::{self, Day, Part},
+    verify::{Check, Status},
+};
+use std::{collections::BTreeMap, fmt::Display, fs};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Cell {
+    /// Produces the recorded answer.
+    Solved,
+    /// Produces an answer, but there is no recorded answer or no input to
+    /// check it with.
+    Unverified,
+    /// Still the `todo!()` of the daily template.
+    Todo,
+    /// Produces a wrong answer, errors, panics or runs out of time.
+    Failing,
+    /// No crate or no module for the part.
+    Missing,
+}
+
+impl Cell {
+    pub const ALL: [Self; 5] = [
+        Self::Solved,
+        Self::Unverified,
+        Self::Todo,
+        Self::Failing,
+        Self::Missing,
+    ];
+
+    #[must_use]
+    pub const fn symbol(self) -> char {
+        match self {
+            Self::Solved => '*',
+            Self::Unverified => '?',
+            Self::Todo => 't',
+            Self::Failing => '!',
+            Self::Missing => '.',
+        }
+    }
+
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Solved => "solved",
+            Self::Unverified => "unverified",
+            Self::Todo => "todo",
+            Self::Failing => "failing",
+            Self::Missing => "missing",
+        }
+    }
+
+    /// Classifies a [`Check`]. Without an input to run on, a part counts as
+    /// [`Cell::Todo`] when its source still calls `todo!()`.
+    #[must_use]
+    pub fn of(check: &Check) -> Self {
+        match check.status {
+            Status::Pass => Self::Solved,
+            Status::Mismatch { .. } | Status::Fail(_) => Self::Failing,
+            Status::Unimplemented if check.day.solver(check.part).is_none() => Self::Missing,
+            Status::Unimplemented => Self::Todo,
+            Status::NoInput if stubbed(check.day, check.part) => Self::Todo,
+            Status::Unrecorded | Status::NoInput => Self::Unverified,
+        }
+    }
+}
+
+/// Whether the `partN.rs` of `day` still calls `todo!()`.
+#[must_use]
+pub fn stubbed(day: &Day, part: Part) -> bool {
+    fs::read_to_string(day.dir().join("src").join(format!("part{part}.rs")))
+        .is_ok_and(|source| source.contains("todo!("))
+}
+
+/// Year × day × part, every puzzle of the years included whether it has a
+/// crate or not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    years: Vec<u16>,
+    cells: BTreeMap<(u16, u8, Part), Cell>,
+}
+
+impl Matrix {
+    #[must_use]
+    pub fn new(years: &[u16], checks: &[Check]) -> Self {
+        let cells = checks
+            .iter()
+            .map(|check| ((check.day.year, check.day.day, check.part), Cell::of(check)))
+            .collect();
+        Self {
+            years: years.to_vec(),
+            cells,
+        }
+    }
+
+    #[must_use]
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Cell {
+        self.cells
+            .get(&(year, day, part))
+            .copied()
+            .unwrap_or(Cell::Missing)
+    }
+
+    /// How many parts of the included years are in `cell`.
+    #[must_use]
+    pub fn count(&self, cell: Cell) -> usize {
+        self.years
+            .iter()
+            .flat_map(|&year| (1..=registry::puzzles(year)).map(move |day| (year, day)))
+            .flat_map(|(year, day)| Part::ALL.map(|part| self.get(year, day, part)))
+            .filter(|&found| found == cell)
+            .count()
+    }
+}
+
+impl Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for &year in &self.years {
+            let days = 1..=registry::puzzles(year);
+            write!(f, "{year}   ")?;
+            for day in days.clone() {
+                write!(f, " {day:02}")?;
+            }
+            writeln!(f)?;
+            for part in Part::ALL {
+                write!(f, "part {part}")?;
+                for day in days.clone() {
+                    write!(f, "  {}", self.get(year, day, part).symbol())?;
+                }
+                writeln!(f)?;
+            }
+            writeln!(f)?;
+        }
+        let legend = Cell::ALL
+            .map(|cell| format!("{} {} {}", cell.symbol(), self.count(cell), cell.label()));
+        write!(f, "{}", legend.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(year: u16, day: u8, part: Part, status: Status) -> Check {
+        Check {
+            day: registry::find(year, day).unwrap(),
+            part,
+            status,
+            answer: None,
+            elapsed: None,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn test_matrix() {
+        let checks = [
+            check(2025, 1, Part::One, Status::Pass),
+            check(2025, 1, Part::Two, Status::Fail("timeout".to_string())),
+            check(2025, 6, Part::Two, Status::NoInput),
+            check(2025, 7, Part::One, Status::NoInput),
+            check(2024, 3, Part::Two, Status::Unimplemented),
+        ];
+        let matrix = Matrix::new(&[2025], &checks);
+        assert_eq!(matrix.get(2025, 1, Part::One), Cell::Solved);
+        assert_eq!(matrix.get(2025, 1, Part::Two), Cell::Failing);
+        assert_eq!(matrix.get(2025, 6, Part::Two), Cell::Todo);
+        assert_eq!(matrix.get(2025, 7, Part::One), Cell::Unverified);
+        assert_eq!(matrix.get(2025, 12, Part::One), Cell::Missing);
+        assert_eq!(Cell::of(&checks[4]), Cell::Missing);
+        assert_eq!(matrix.count(Cell::Missing), 20);
+
+        assert_eq!(
+            matrix.to_string(),
+            "2025    01 02 03 04 05 06 07 08 09 10 11 12
+part 1  *  .  .  .  .  .  ?  .  .  .  .  .
+part 2  !  .  .  .  .  t  .  .  .  .  .  .
+
+* 1 solved, ? 1 unverified, t 1 todo, ! 1 failing, . 20 missing"
+        );
+    }
+}
@@ -0,0 +1,383 @@
+//! Submitting answers, and remembering every guess so a known-wrong answer is
+//! never sent twice.
+//!
+//! Guesses are kept per part in the [`Archive`]. Before sending an answer it
+//! is checked against them: an answer already judged wrong, or beyond an
+//! answer judged too high or too low, is refused without asking the site.
+
+use crate::{
+    archive::{Archive, ArchiveError},
+    client::{Client, ClientError},
+    registry::{Answer, Part},
+};
+use miette::Diagnostic;
+use std::{
+    fmt::Display,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use thiserror::Error;
+
+/// How the site judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying which way.
+    Wrong,
+    /// Not judged, another answer was sent too recently.
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// Not judged, the part is solved already.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict off the page the site replies with, `None` when it
+    /// says something else.
+    #[must_use]
+    pub fn parse(html: &str) -> Option<Self> {
+        let text = article(html);
+        if text.contains("That's the right answer") {
+            Some(Self::Correct)
+        } else if text.contains("You gave an answer too recently") {
+            Some(Self::RateLimited { wait: wait(&text) })
+        } else if text.contains("That's not the right answer") {
+            Some(if text.contains("too high") {
+                Self::TooHigh
+            } else if text.contains("too low") {
+                Self::TooLow
+            } else {
+                Self::Wrong
+            })
+        } else if text.contains("You don't seem to be solving the right level") {
+            Some(Self::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// The name the verdict is archived under, the wait of a rate limit
+    /// left out.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Correct => "correct",
+            Self::TooHigh => "too_high",
+            Self::TooLow => "too_low",
+            Self::Wrong => "wrong",
+            Self::RateLimited { .. } => "rate_limited",
+            Self::AlreadySolved => "already_solved",
+        }
+    }
+
+    /// The verdict archived as `name`, rate limited for `wait`.
+    #[must_use]
+    pub fn from_name(name: &str, wait: Option<Duration>) -> Option<Self> {
+        Some(match name {
+            "correct" => Self::Correct,
+            "too_high" => Self::TooHigh,
+            "too_low" => Self::TooLow,
+            "wrong" => Self::Wrong,
+            "rate_limited" => Self::RateLimited { wait },
+            "already_solved" => Self::AlreadySolved,
+            _ => return None,
+        })
+    }
+
+    /// Whether the answer was judged wrong, in whatever way.
+    #[must_use]
+    pub const fn is_wrong(&self) -> bool {
+        matches!(self, Self::TooHigh | Self::TooLow | Self::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::TooHigh => write!(f, "wrong, too high"),
+            Self::TooLow => write!(f, "wrong, too low"),
+            Self::Wrong => write!(f, "wrong"),
+            Self::RateLimited { wait: Some(wait) } => {
+                write!(f, "not judged, wait {}s first", wait.as_secs())
+            }
+            Self::RateLimited { wait: None } => write!(f, "not judged, wait a little first"),
+            Self::AlreadySolved => write!(f, "not judged, the part is solved already"),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, without tags.
+fn article(html: &str) -> String {
+    let body = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(html, |(_, rest)| {
+            rest.split_once("</article>").map_or(rest, |(body, _)| body)
+        });
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in body.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// The wait of "You have 1m 4s left to wait".
+fn wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (left, _) = rest.split_once(" left to wait")?;
+    left.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number = number.parse::<u64>().ok()?;
+            match unit {
+                "s" => Some(number),
+                "m" => Some(number * 60),
+                "h" => Some(number * 3600),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess {
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub at: u64,
+}
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum SubmitError {
+    #[error("{year} day {day:02} part {part} is solved already, with {answer}")]
+    #[diagnostic(code(aoc::submit::solved))]
+    Solved {
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: Answer,
+    },
+    #[error("{answer} was already judged {verdict}")]
+    #[diagnostic(code(aoc::submit::known))]
+    Known { answer: Answer, verdict: Verdict },
+    #[error("{answer} is out of bounds, {bound} was already judged {verdict}")]
+    #[diagnostic(
+        code(aoc::submit::bound),
+        help("answers too high or too low bound every later guess")
+    )]
+    OutOfBounds {
+        answer: Answer,
+        bound: Answer,
+        verdict: Verdict,
+    },
+    #[error("the reply to submitting {answer} says neither right nor wrong")]
+    #[diagnostic(code(aoc::submit::unrecognized))]
+    Unrecognized { answer: Answer },
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Client(#[from] ClientError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Archive(#[from] ArchiveError),
+}
+
+/// Checks that `answer` is worth submitting to `part` of `day` of `year`
+/// given its earlier `guesses`.
+///
+/// # Errors
+///
+/// When the part is solved, `answer` was judged wrong before, or it lies
+/// beyond an answer judged too high or too low.
+pub fn check(
+    guesses: &[Guess],
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<(), SubmitError> {
+    if let Some(guess) = guesses
+        .iter()
+        .find(|guess| guess.verdict == Verdict::Correct)
+    {
+        return Err(SubmitError::Solved {
+            year,
+            day,
+            part,
+            answer: guess.answer.clone(),
+        });
+    }
+    if let Some(guess) = guesses
+        .iter()
+        .find(|guess| guess.verdict.is_wrong() && guess.answer == *answer)
+    {
+        return Err(SubmitError::Known {
+            answer: answer.clone(),
+            verdict: guess.verdict.clone(),
+        });
+    }
+    let Some(value) = number(answer) else {
+        return Ok(());
+    };
+    for guess in guesses {
+        let Some(bound) = number(&guess.answer) else {
+            continue;
+        };
+        let beyond = match guess.verdict {
+            Verdict::TooHigh => value >= bound,
+            Verdict::TooLow => value <= bound,
+            _ => false,
+        };
+        if beyond {
+            return Err(SubmitError::OutOfBounds {
+                answer: answer.clone(),
+                bound: guess.answer.clone(),
+                verdict: guess.verdict.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Integer answers as one type, so bounds compare across signedness.
+fn number(answer: &Answer) -> Option<i128> {
+    match *answer {
+        Answer::Int(int) => Some(int),
+        Answer::UInt(uint) => i128::try_from(uint).ok(),
+        Answer::Text(_) | Answer::Grid(_) => None,
+    }
+}
+
+/// Submits `answer` to `part` of `day` of `year` unless the guesses in the
+/// `archive` rule it out, and records the verdict there.
+///
+/// # Errors
+///
+/// When the guesses rule the answer out, the site can't be reached, its
+/// reply isn't understood or the archive can't be read or written.
+pub fn submit(
+    client: &Client,
+    archive: &Archive,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &Answer,
+) -> Result<Verdict, SubmitError> {
+    check(&archive.guesses(year, day, part)?, year, day, part, answer)?;
+    let level = part.number().to_string();
+    let html = client.post(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &level), ("answer", &answer.to_string())],
+    )?;
+    let verdict = Verdict::parse(&html).ok_or_else(|| SubmitError::Unrecognized {
+        answer: answer.clone(),
+    })?;
+    let at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let guess = Guess {
+        answer: answer.clone(),
+        verdict,
+        at,
+    };
+    archive.record(year, day, part, &guess)?;
+    Ok(guess.verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |article| Verdict::parse(&page(article));
+        assert_eq!(
+            parse("That's the right answer! You are <em>one gold star</em> closer."),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too high. Please wait one minute."),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low."),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            parse(
+                "That's not the right answer. If you're stuck, make sure you're using the full input data."
+            ),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            parse(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 4s left to wait."
+            ),
+            Some(Verdict::RateLimited {
+                wait: Some(Duration::from_secs(64))
+            })
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it?"),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(parse("Something else entirely"), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut guesses = Vec::new();
+        let mut record = |answer: i64, verdict| {
+            guesses.push(Guess {
+                answer: Answer::from(answer),
+                verdict,
+                at: 0,
+            });
+        };
+        record(100, Verdict::TooHigh);
+        record(10, Verdict::TooLow);
+        record(42, Verdict::Wrong);
+        record(43, Verdict::RateLimited { wait: None });
+        let check = |guesses: &[Guess], answer: i64| {
+            check(guesses, 2025, 1, Part::One, &Answer::from(answer))
+        };
+        assert!(check(&[], 50).is_ok());
+        assert!(matches!(
+            check(&guesses, 42),
+            Err(SubmitError::Known { .. })
+        ));
+        assert!(matches!(
+            check(&guesses, 101),
+            Err(SubmitError::OutOfBounds { .. })
+        ));
+        assert!(matches!(
+            check(&guesses, 5),
+            Err(SubmitError::OutOfBounds { .. })
+        ));
+        assert!(check(&guesses, 43).is_ok());
+
+        guesses.push(Guess {
+            answer: Answer::Int(43),
+            verdict: Verdict::Correct,
+            at: 0,
+        });
+        assert!(matches!(
+            check(&guesses, 44),
+            Err(SubmitError::Solved { .. })
+        ));
+    }
+}
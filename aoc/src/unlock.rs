@@ -0,0 +1,269 @@
+//! When the next puzzle unlocks, waiting for it, and scaffolding its crate.
+//!
+//! Puzzles unlock at midnight UTC-5, one a day from December 1st. Time comes
+//! from a [`Clock`], so waiting can be tested without waiting.
+
+use crate::registry;
+use jiff::{
+    SignedDuration, Timestamp,
+    civil::Date,
+    tz::{self, TimeZone},
+};
+use miette::Diagnostic;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+use thiserror::Error;
+
+/// Longest nap between two looks at the time left.
+pub const TICK: SignedDuration = SignedDuration::from_secs(1);
+
+/// Time given to the site after an unlock before asking it for the puzzle.
+pub const GRACE: SignedDuration = SignedDuration::from_secs(2);
+
+/// Template files only `cargo generate` reads.
+const TEMPLATE_ONLY: [&str; 1] = ["cargo-generate.toml"];
+
+pub trait Clock {
+    fn now(&self) -> Timestamp;
+    fn sleep(&self, duration: Duration);
+}
+
+/// The clock on the wall.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Timestamp {
+        Timestamp::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+#[derive(Debug, Error, Diagnostic)]
+pub enum ScaffoldError {
+    #[error("{} already exists", .dir.display())]
+    #[diagnostic(code(aoc::unlock::exists))]
+    Exists { dir: PathBuf },
+    #[error("no template at {}", .dir.display())]
+    #[diagnostic(
+        code(aoc::unlock::template),
+        help("a new year starts by copying the previous year's workspace and `daily-template`")
+    )]
+    NoTemplate { dir: PathBuf },
+    #[error("failed to copy {}", .path.display())]
+    #[diagnostic(code(aoc::unlock::copy))]
+    Copy {
+        path: PathBuf,
+        #[source]
+        error: io::Error,
+    },
+}
+
+const fn eastern() -> TimeZone {
+    TimeZone::fixed(tz::offset(-5))
+}
+
+/// When `day` of `year` unlocks, `None` for days that don't exist.
+#[must_use]
+pub fn unlock(year: u16, day: u8) -> Option<Timestamp> {
+    if !(1..=registry::puzzles(year)).contains(&day) {
+        return None;
+    }
+    let date = Date::new(i16::try_from(year).ok()?, 12, i8::try_from(day).ok()?).ok()?;
+    Some(date.to_zoned(eastern()).ok()?.timestamp())
+}
+
+/// The first puzzle unlocking after `now`: year, day and when.
+///
+/// # Panics
+///
+/// When `now` is before year 0.
+#[must_use]
+pub fn next(now: Timestamp) -> (u16, u8, Timestamp) {
+    let today = now.to_zoned(eastern());
+    let year = u16::try_from(today.year()).expect("Advent of Code years are positive");
+    let (year, day) = match (today.month(), u8::try_from(today.day())) {
+        (12, Ok(day)) if day < registry::puzzles(year) => (year, day + 1),
+        (12, _) => (year + 1, 1),
+        _ => (year, 1),
+    };
+    (
+        year,
+        day,
+        unlock(year, day).expect("the next puzzle exists"),
+    )
+}
+
+/// Sleeps on `clock` until `target`, calling `tick` with the time left before
+/// every nap of at most [`TICK`].
+pub fn wait_until(clock: &impl Clock, target: Timestamp, mut tick: impl FnMut(SignedDuration)) {
+    loop {
+        let left = target.duration_since(clock.now());
+        if !left.is_positive() {
+            return;
+        }
+        tick(left);
+        clock.sleep(left.min(TICK).unsigned_abs());
+    }
+}
+
+/// `2d 03:12:05`, or `03:12:05` under a day.
+#[must_use]
+pub fn countdown(left: SignedDuration) -> String {
+    let seconds = left.as_secs().max(0);
+    let clock = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60
+    );
+    match seconds / 86_400 {
+        0 => clock,
+        days => format!("{days}d {clock}"),
+    }
+}
+
+/// Creates the crate of `day` in `dir` from the `cargo generate` template in
+/// `template`, filling in its placeholders.
+///
+/// # Errors
+///
+/// When `dir` exists, there is no template, or it can't be copied.
+pub fn scaffold(template: &Path, dir: &Path, day: u8) -> Result<(), ScaffoldError> {
+    if dir.exists() {
+        return Err(ScaffoldError::Exists {
+            dir: dir.to_path_buf(),
+        });
+    }
+    if !template.is_dir() {
+        return Err(ScaffoldError::NoTemplate {
+            dir: template.to_path_buf(),
+        });
+    }
+    let name = format!("day-{day:02}");
+    let fill = |text: String| {
+        text.replace("{{project-name}}", &name)
+            .replace("{{crate_name}}", &name.replace('-', "_"))
+            .replace("day xx", &format!("day {day:02}"))
+    };
+    copy(template, dir, &fill)
+}
+
+fn copy(from: &Path, to: &Path, fill: &impl Fn(String) -> String) -> Result<(), ScaffoldError> {
+    let error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |error| ScaffoldError::Copy { path, error }
+    };
+    fs::create_dir_all(to).map_err(error(to))?;
+    for entry in fs::read_dir(from).map_err(error(from))? {
+        let path = entry.map_err(error(from))?.path();
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        if TEMPLATE_ONLY.iter().any(|only| name == *only) {
+            continue;
+        }
+        if path.is_dir() {
+            copy(&path, &to.join(name), fill)?;
+        } else {
+            let text = fs::read_to_string(&path).map_err(error(&path))?;
+            fs::write(to.join(name), fill(text)).map_err(error(&path))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, env};
+
+    /// A clock whose naps pass instantly.
+    struct Fake(Cell<Timestamp>);
+
+    impl Clock for Fake {
+        fn now(&self) -> Timestamp {
+            self.0.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.0.set(self.0.get() + duration);
+        }
+    }
+
+    fn at(text: &str) -> Timestamp {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn test_next() {
+        assert_eq!(unlock(2025, 1), Some(at("2025-12-01T05:00:00Z")));
+        assert_eq!(unlock(2025, 13), None);
+        assert_eq!(unlock(2024, 25), Some(at("2024-12-25T05:00:00Z")));
+
+        assert_eq!(
+            next(at("2025-07-01T00:00:00Z")),
+            (2025, 1, at("2025-12-01T05:00:00Z"))
+        );
+        // still November 30th in UTC-5
+        assert_eq!(next(at("2025-12-01T04:59:59Z")).1, 1);
+        assert_eq!(next(at("2025-12-01T05:00:00Z")).1, 2);
+        assert_eq!(
+            next(at("2025-12-12T12:00:00Z")),
+            (2026, 1, at("2026-12-01T05:00:00Z"))
+        );
+        assert_eq!(next(at("2024-12-12T12:00:00Z")).1, 13);
+    }
+
+    #[test]
+    fn test_wait_until() {
+        let clock = Fake(Cell::new(at("2025-12-01T04:59:57.500Z")));
+        let mut ticks = Vec::new();
+        wait_until(&clock, at("2025-12-01T05:00:00Z"), |left| {
+            ticks.push(countdown(left));
+        });
+        assert_eq!(clock.now(), at("2025-12-01T05:00:00Z"));
+        assert_eq!(ticks, ["00:00:02", "00:00:01", "00:00:00"]);
+        assert_eq!(
+            countdown(SignedDuration::from_hours(50) + SignedDuration::from_secs(5)),
+            "2d 02:00:05"
+        );
+    }
+
+    #[test]
+    fn test_scaffold() -> Result<(), ScaffoldError> {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let (template, dir) = (root.join("daily-template"), root.join("day-05"));
+        fs::create_dir_all(template.join("src")).unwrap();
+        fs::write(template.join("Cargo.toml"), "name = \"{{project-name}}\"\n").unwrap();
+        fs::write(
+            template.join("src").join("part1.rs"),
+            "use {{crate_name}}::Puzzle;\ntodo!(\"day xx - part 1\");\n",
+        )
+        .unwrap();
+        fs::write(template.join("cargo-generate.toml"), "[template]\n").unwrap();
+
+        scaffold(&template, &dir, 5)?;
+        let read = |path: PathBuf| fs::read_to_string(path).unwrap();
+        assert_eq!(read(dir.join("Cargo.toml")), "name = \"day-05\"\n");
+        assert_eq!(
+            read(dir.join("src").join("part1.rs")),
+            "use day_05::Puzzle;\ntodo!(\"day 05 - part 1\");\n"
+        );
+        assert!(!dir.join("cargo-generate.toml").exists());
+        assert!(matches!(
+            scaffold(&template, &dir, 5),
+            Err(ScaffoldError::Exists { .. })
+        ));
+
+        fs::remove_dir_all(root).unwrap();
+        Ok(())
+    }
+}
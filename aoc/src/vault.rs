@@ -0,0 +1,147 @@
+//! Moving a year's inputs and answers in and out of the encrypted
+//! [`aoc_core::vault`], so they can be committed.
+//!
+//! Every day reads the same input for both parts, so the vault holds one per
+//! day: `inputs/<year>/day-NN.txt.enc`, beside the year's
+//! `inputs/<year>/answers.toml.enc`.
+
+use crate::{answers, input};
+use aoc_core::vault;
+pub use aoc_core::vault::{Key, VaultError, input_path, path};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The plaintext input of the crate in `dir`, if it has one.
+fn plaintext(dir: &Path) -> Option<PathBuf> {
+    ["input1.txt", input::SHARED]
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day-{day:02}"))
+}
+
+fn read(path: &Path) -> Result<String, VaultError> {
+    fs::read_to_string(path).map_err(|error| VaultError::Io {
+        path: path.to_path_buf(),
+        error,
+    })
+}
+
+/// Encrypts the plaintext inputs of `days` of `year` and the year's answers
+/// under the repository `root`, returning the files written.
+///
+/// Days without an input are skipped, earlier encrypted copies replaced.
+///
+/// # Errors
+///
+/// When a plaintext can't be read or its copy written.
+pub fn encrypt(key: &Key, root: &Path, year: u16, days: &[u8]) -> Result<Vec<PathBuf>, VaultError> {
+    let mut written = Vec::new();
+    let answers = root.join(year.to_string()).join(answers::FILE);
+    let files = days
+        .iter()
+        .filter_map(|&day| {
+            let dir = day_dir(root, year, day);
+            Some((plaintext(&dir)?, input_path(&dir)?))
+        })
+        .chain(
+            answers
+                .is_file()
+                .then(|| (answers, path(root, &year.to_string(), answers::FILE))),
+        );
+    for (plain, sealed) in files {
+        vault::write(key, &sealed, &read(&plain)?)?;
+        written.push(sealed);
+    }
+    Ok(written)
+}
+
+/// Decrypts the inputs of `days` of `year` into their crates as the
+/// [`input::SHARED`] input, and the year's answers, returning the files
+/// written.
+///
+/// Plaintexts that exist already are kept.
+///
+/// # Errors
+///
+/// When an encrypted copy can't be decrypted or its plaintext written.
+pub fn decrypt(root: &Path, year: u16, days: &[u8]) -> Result<Vec<PathBuf>, VaultError> {
+    let mut written = Vec::new();
+    let answers = root.join(year.to_string()).join(answers::FILE);
+    let files = days
+        .iter()
+        .filter_map(|&day| {
+            let dir = day_dir(root, year, day);
+            if plaintext(&dir).is_some() {
+                return None;
+            }
+            Some((input_path(&dir)?, dir.join(input::SHARED)))
+        })
+        .chain(
+            (!answers.exists()).then(|| (path(root, &year.to_string(), answers::FILE), answers)),
+        );
+    for (sealed, plain) in files {
+        if !sealed.is_file() {
+            continue;
+        }
+        let text = vault::read(&sealed)?;
+        fs::write(&plain, text).map_err(|error| VaultError::Io {
+            path: plain.clone(),
+            error,
+        })?;
+        written.push(plain);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_round_trip() -> Result<(), VaultError> {
+        let root = env::temp_dir().join(format!("aoc-vault-{}", std::process::id()));
+        let year = root.join("2025");
+        for day in ["day-01", "day-02", "day-03"] {
+            fs::create_dir_all(year.join(day)).unwrap();
+        }
+        fs::write(year.join("day-01").join("input.txt"), "L68\n").unwrap();
+        fs::write(year.join("day-02").join("input1.txt"), "11-22\n").unwrap();
+        fs::write(year.join(answers::FILE), "[day-01]\npart1 = 3\n").unwrap();
+        fs::write(root.join(vault::KEY_FILE), "hunter2\n").unwrap();
+        let key = Key::find(&path(&root, "2025", "day-01.txt"))?;
+
+        let sealed = encrypt(&key, &root, 2025, &[1, 2, 3])?;
+        let inputs = root.join("inputs").join("2025");
+        assert_eq!(
+            sealed,
+            [
+                inputs.join("day-01.txt.enc"),
+                inputs.join("day-02.txt.enc"),
+                inputs.join("answers.toml.enc"),
+            ]
+        );
+
+        fs::remove_file(year.join("day-01").join("input.txt")).unwrap();
+        fs::remove_file(year.join(answers::FILE)).unwrap();
+        let plain = decrypt(&root, 2025, &[1, 2, 3])?;
+        assert_eq!(
+            plain,
+            [
+                year.join("day-01").join("input.txt"),
+                year.join(answers::FILE)
+            ]
+        );
+        assert_eq!(read(&plain[0])?, "L68\n");
+        assert_eq!(read(&plain[1])?, "[day-01]\npart1 = 3\n");
+
+        fs::remove_dir_all(root).unwrap();
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+//! Checking the solutions against the recorded [`Answers`].
+
+use crate::{
+    answers::{Answers, AnswersError},
+    archive::Archive,
+    input::{self, InputError},
+    registry::{Answer, Day, Part},
+    run::Runner,
+    vault::VaultError,
+};
+use rayon::prelude::*;
+use std::{
+    collections::{HashMap, hash_map::Entry},
+    fmt::Display,
+    time::Duration,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Mismatch {
+        expected: Answer,
+    },
+    /// The solver errored, panicked, timed out or crashed, or its input can't
+    /// be read.
+    Fail(String),
+    Unimplemented,
+    /// Inputs are git-ignored, so a fresh clone has none to verify with.
+    NoInput,
+    /// Solved, but there is no accepted answer to compare with.
+    Unrecorded,
+}
+
+impl Status {
+    /// Whether the status should fail a verification run.
+    #[must_use]
+    pub const fn is_failure(&self) -> bool {
+        matches!(self, Self::Mismatch { .. } | Self::Fail(_))
+    }
+
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Mismatch { .. } => "mismatch",
+            Self::Fail(_) => "fail",
+            Self::Unimplemented => "unimplemented",
+            Self::NoInput => "no input",
+            Self::Unrecorded => "unrecorded",
+        }
+    }
+}
+
+impl Status {
+    /// What went wrong, for the statuses that carry more than their label.
+    #[must_use]
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::Mismatch { expected } => Some(format!("expected {expected}")),
+            Self::Fail(reason) => Some(reason.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub day: &'static Day,
+    pub part: Part,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    pub elapsed: Option<Duration>,
+    /// The answer came from the cache rather than a fresh run.
+    pub cached: bool,
+}
+
+/// Runs `part` of `day` on its default input and compares the result with the
+/// recorded answer.
+#[must_use]
+pub fn check(day: &'static Day, part: Part, answers: &Answers, runner: Runner) -> Check {
+    let mut check = Check {
+        day,
+        part,
+        status: Status::Unimplemented,
+        answer: None,
+        elapsed: None,
+        cached: false,
+    };
+    if day.solver(part).is_none() {
+        return check;
+    }
+    let input = match input::read(day, part) {
+        Ok(input) => input,
+        Err(InputError::Missing { .. } | InputError::Vault(VaultError::NoKey { .. })) => {
+            check.status = Status::NoInput;
+            return check;
+        }
+        Err(err) => {
+            check.status = Status::Fail(err.to_string());
+            return check;
+        }
+    };
+
+    let Some(outcome) = runner.solve(day, part, &input.text) else {
+        return check;
+    };
+    check.elapsed = Some(outcome.elapsed);
+    check.cached = outcome.cached;
+    check.status = match outcome.answer {
+        Ok(result) => {
+            let status = match answers.get(day.day, part) {
+                Some(expected) if *expected == result.answer => Status::Pass,
+                Some(expected) => Status::Mismatch {
+                    expected: expected.clone(),
+                },
+                None => Status::Unrecorded,
+            };
+            check.answer = Some(result.answer);
+            status
+        }
+        Err(failure) if failure.is_unimplemented() => Status::Unimplemented,
+        Err(failure) => Status::Fail(failure.to_string()),
+    };
+    check
+}
+
+/// Checks `parts` of every one of `days` in parallel, keeping their order.
+///
+/// Answers missing from `answers.toml` are taken from the `archive`, when
+/// given one.
+///
+/// # Errors
+///
+/// When the answers file of one of the years or the archive can't be read.
+pub fn check_all(
+    days: &[&'static Day],
+    parts: &[Part],
+    runner: Runner,
+    archive: Option<&Archive>,
+) -> Result<Vec<Check>, AnswersError> {
+    let mut answers = HashMap::new();
+    for day in days {
+        if let Entry::Vacant(entry) = answers.entry(day.year) {
+            let mut recorded = Answers::load(day.year)?;
+            if let Some(archive) = archive {
+                recorded.fill(archive.accepted(day.year)?);
+            }
+            entry.insert(recorded);
+        }
+    }
+    let checks = days
+        .iter()
+        .flat_map(|&day| parts.iter().map(move |&part| (day, part)))
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|(day, part)| check(day, part, &answers[&day.year], runner))
+        .collect();
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_check_all() -> Result<(), AnswersError> {
+        // 2023 inputs are committed, 2024 day 3 has no second part
+        let days = [
+            registry::find(2023, 1).unwrap(),
+            registry::find(2023, 2).unwrap(),
+            registry::find(2024, 3).unwrap(),
+        ];
+        let checks = check_all(&days, &Part::ALL, Runner::default(), None)?;
+        let statuses = checks
+            .iter()
+            .map(|check| (check.day.day, check.part.number(), check.status.label()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            statuses[..4],
+            [
+                (1, 1, "pass"),
+                (1, 2, "pass"),
+                (2, 1, "pass"),
+                (2, 2, "pass")
+            ]
+        );
+        assert_eq!(statuses[5], (3, 2, "unimplemented"));
+        assert_eq!(checks[0].answer, Some(Answer::Int(55712)));
+        Ok(())
+    }
+}
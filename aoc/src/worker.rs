@@ -0,0 +1,234 @@
+//! Running a part in a child process of `aoc` itself, so a part that never
+//! finishes can be killed once its budget runs out and one that crashes can't
+//! take the rest of the run down with it.
+//!
+//! The child is started as `aoc worker <year> <day> <part> --preset <preset>`,
+//! reads the input from stdin and reports a [`Reply`] as JSON on the last line
+//! of stdout; anything the solution prints itself comes before it.
+
+use crate::{
+    registry::{self, Answer, Day, Part, Preset, Solved},
+    run::{self, Failure, Outcome},
+};
+use aoc_core::solution::Timings;
+use miette::{IntoDiagnostic, Result, miette};
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    io::{self, Read, Write},
+    process::{Command, Stdio},
+    thread,
+    time::Duration,
+};
+use wait_timeout::ChildExt;
+
+/// Name of the hidden subcommand the child runs.
+pub const COMMAND: &str = "worker";
+
+/// Arguments of the hidden subcommand, shared by every binary that runs
+/// parts in workers.
+#[derive(Debug, Clone, Copy, clap::Args)]
+pub struct Args {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    #[arg(long, default_value_t)]
+    pub preset: Preset,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum Reply {
+    Solved {
+        answer: Answer,
+        timings: Option<(Duration, Duration)>,
+        elapsed: Duration,
+    },
+    Error {
+        message: String,
+        elapsed: Duration,
+    },
+    Panic {
+        message: String,
+        elapsed: Duration,
+    },
+}
+
+impl From<Outcome> for Reply {
+    fn from(outcome: Outcome) -> Self {
+        let elapsed = outcome.elapsed;
+        match outcome.answer {
+            Ok(Solved { answer, timings }) => Self::Solved {
+                answer,
+                timings: timings.map(|Timings { parse, solve }| (parse, solve)),
+                elapsed,
+            },
+            Err(Failure::Panic(message)) => Self::Panic { message, elapsed },
+            Err(failure) => Self::Error {
+                message: failure.to_string(),
+                elapsed,
+            },
+        }
+    }
+}
+
+impl From<Reply> for Outcome {
+    fn from(reply: Reply) -> Self {
+        match reply {
+            Reply::Solved {
+                answer,
+                timings,
+                elapsed,
+            } => Self {
+                answer: Ok(Solved {
+                    answer,
+                    timings: timings.map(|(parse, solve)| Timings { parse, solve }),
+                }),
+                elapsed,
+                cached: false,
+            },
+            Reply::Error { message, elapsed } => Self {
+                answer: Err(Failure::Error(miette!("{message}"))),
+                elapsed,
+                cached: false,
+            },
+            Reply::Panic { message, elapsed } => Self {
+                answer: Err(Failure::Panic(message)),
+                elapsed,
+                cached: false,
+            },
+        }
+    }
+}
+
+/// Body of the worker process: solves the part in `args` on stdin.
+///
+/// # Errors
+///
+/// When the day or part isn't registered or stdin can't be read.
+pub fn serve(args: Args) -> Result<()> {
+    let Args {
+        year,
+        day,
+        part,
+        preset,
+    } = args;
+    let solver = registry::find(year, day)
+        .and_then(|day| day.solver(part))
+        .ok_or_else(|| miette!("no solver for {year} day {day:02} part {part}"))?;
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).into_diagnostic()?;
+
+    let reply = Reply::from(run::run(solver, &input, preset));
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout).into_diagnostic()?;
+    serde_json::to_writer(&mut stdout, &reply).into_diagnostic()?;
+    writeln!(stdout).into_diagnostic()
+}
+
+/// Solves `part` of `day` on `input` in a worker process, killing it once
+/// `budget` is exceeded.
+#[must_use]
+pub fn run(day: &Day, part: Part, input: &str, preset: Preset, budget: Duration) -> Outcome {
+    match spawn(day, part, input, preset, budget) {
+        Ok(outcome) => outcome,
+        Err(err) => Outcome {
+            answer: Err(Failure::Crash(err.to_string())),
+            elapsed: Duration::ZERO,
+            cached: false,
+        },
+    }
+}
+
+fn spawn(day: &Day, part: Part, input: &str, preset: Preset, budget: Duration) -> Result<Outcome> {
+    let mut child = Command::new(env::current_exe().into_diagnostic()?)
+        .args([
+            COMMAND,
+            &day.year.to_string(),
+            &day.day.to_string(),
+            &part.to_string(),
+            "--preset",
+            preset.name(),
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .into_diagnostic()?;
+
+    // feed and drain the pipes on their own threads, a chatty solution would
+    // otherwise block on a full pipe while we wait for it
+    let mut stdin = child
+        .stdin
+        .take()
+        .ok_or_else(|| miette!("no worker stdin"))?;
+    let input = input.to_string();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let mut stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| miette!("no worker stdout"))?;
+    let reader = thread::spawn(move || {
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).map(|_| output)
+    });
+
+    let Some(status) = child.wait_timeout(budget).into_diagnostic()? else {
+        child.kill().into_diagnostic()?;
+        child.wait().into_diagnostic()?;
+        return Ok(Outcome {
+            answer: Err(Failure::Timeout(budget)),
+            elapsed: budget,
+            cached: false,
+        });
+    };
+    // the worker may exit before reading all of a large input
+    let _ = writer.join();
+    let output = reader
+        .join()
+        .map_err(|_| miette!("worker output reader panicked"))?
+        .into_diagnostic()?;
+
+    let reply = output
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .and_then(|line| serde_json::from_str::<Reply>(line).ok());
+    reply
+        .map(Outcome::from)
+        .ok_or_else(|| miette!("worker exited with {status} without an answer"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reply_roundtrip() {
+        let outcome = Outcome {
+            answer: Ok(Solved {
+                answer: Answer::Int(42),
+                timings: Some(Timings {
+                    parse: Duration::from_millis(1),
+                    solve: Duration::from_millis(2),
+                }),
+            }),
+            elapsed: Duration::from_millis(3),
+            cached: false,
+        };
+        let json = serde_json::to_string(&Reply::from(outcome)).unwrap();
+        let outcome = Outcome::from(serde_json::from_str::<Reply>(&json).unwrap());
+        let solved = outcome.answer.unwrap();
+        assert_eq!(solved.answer, Answer::Int(42));
+        assert_eq!(solved.timings.unwrap().solve, Duration::from_millis(2));
+        assert_eq!(outcome.elapsed, Duration::from_millis(3));
+
+        let outcome = Outcome {
+            answer: Err(Failure::Panic("not yet implemented".to_string())),
+            elapsed: Duration::ZERO,
+            cached: false,
+        };
+        let json = serde_json::to_string(&Reply::from(outcome)).unwrap();
+        let outcome = Outcome::from(serde_json::from_str::<Reply>(&json).unwrap());
+        assert!(outcome.answer.unwrap_err().is_unimplemented());
+    }
+}
@@ -0,0 +1,144 @@
+//! The client end to end against the stand-in site, failures included.
+
+use aoc::{
+    archive::Archive,
+    client::{Client, ClientError},
+    fetch::{self, FetchError},
+    leaderboard::Leaderboard,
+    mock::{MODE_PATH, Mode},
+    registry::{Answer, Part},
+    submit::{self, SubmitError, Verdict},
+};
+use std::{
+    env, fs,
+    io::{BufRead, BufReader},
+    path::PathBuf,
+    process::{Child, Command, Stdio},
+    time::Duration,
+};
+
+struct Mock {
+    child: Child,
+    url: String,
+    root: PathBuf,
+}
+
+impl Mock {
+    /// Starts the stand-in, with a scratch directory named after `test`.
+    fn start(test: &str) -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc-mock"))
+            .args(["--addr", "127.0.0.1:0", "--session", "abc"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let url = line
+            .trim()
+            .strip_prefix("listening on ")
+            .unwrap()
+            .to_string();
+        let root = env::temp_dir().join(format!("aoc-mock-{test}-{}", std::process::id()));
+        fs::create_dir_all(root.join("day-01")).unwrap();
+        Self { child, url, root }
+    }
+
+    fn client(&self, session: &str) -> Client {
+        Client::new(session)
+            .with_base_url(&self.url)
+            .with_interval(Duration::ZERO)
+            .with_stamp(None)
+    }
+
+    fn switch(&self, mode: Mode) {
+        ureq::put(&format!("{}{MODE_PATH}", self.url))
+            .send_string(&mode.to_string())
+            .unwrap();
+    }
+}
+
+impl Drop for Mock {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+#[test]
+fn test_client_against_mock() -> miette::Result<()> {
+    let mock = Mock::start("client");
+    let client = mock.client("abc");
+    let archive = Archive::memory()?;
+    let (cache, dir) = (mock.root.join("cache"), mock.root.join("day-01"));
+
+    let fetched = fetch::fetch(&client, &cache, &archive, 2025, 1, &dir)?;
+    assert!(!fetched.cached);
+    assert!(
+        fs::read_to_string(&fetched.path)
+            .unwrap()
+            .starts_with("L68\n")
+    );
+    let written = fetch::puzzle(&client, &archive, 2025, 1, &dir)?;
+    assert!(written.contains(&dir.join("examples").join("example.part2")));
+
+    let json = fetch::leaderboard(&client, &cache, 2025, 1)?;
+    assert!(Leaderboard::parse(&json)?.render().contains("Alice"));
+
+    let submit =
+        |part, answer: i64| submit::submit(&client, &archive, 2025, 1, part, &Answer::from(answer));
+    assert_eq!(submit(Part::One, 2)?, Verdict::TooLow);
+    assert!(matches!(
+        submit(Part::One, 1),
+        Err(SubmitError::OutOfBounds { .. })
+    ));
+    assert_eq!(submit(Part::One, 3)?, Verdict::Correct);
+    assert_eq!(submit(Part::Two, 6)?, Verdict::Correct);
+    assert_eq!(archive.accepted(2025)?.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_failure_modes() -> miette::Result<()> {
+    let mock = Mock::start("failures");
+    let archive = Archive::memory()?;
+    let cache = mock.root.join("cache");
+    let status = |result: Result<(String, bool), FetchError>| match result {
+        Err(FetchError::Client(ClientError::Status { status, .. })) => Some(status),
+        _ => None,
+    };
+
+    let stranger = mock.client("xyz");
+    assert_eq!(
+        status(fetch::download(&stranger, &cache, &archive, 2025, 1)),
+        Some(400)
+    );
+
+    let client = mock.client("abc");
+    mock.switch(Mode::Locked);
+    assert_eq!(
+        status(fetch::download(&client, &cache, &archive, 2025, 1)),
+        Some(404)
+    );
+
+    mock.switch(Mode::Expired);
+    assert_eq!(
+        status(fetch::download(&client, &cache, &archive, 2025, 1)),
+        Some(400)
+    );
+
+    mock.switch(Mode::RateLimited);
+    let verdict = submit::submit(&client, &archive, 2025, 1, Part::One, &Answer::Int(3))?;
+    assert_eq!(
+        verdict,
+        Verdict::RateLimited {
+            wait: Some(Duration::from_secs(64))
+        }
+    );
+
+    mock.switch(Mode::Normal);
+    assert!(!fetch::download(&client, &cache, &archive, 2025, 1)?.1);
+    Ok(())
+}
@@ -0,0 +1,77 @@
+//! The HTTP server end to end, on a free port of localhost.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    process::{Child, Command, Stdio},
+};
+
+struct Server {
+    child: Child,
+    addr: String,
+}
+
+impl Server {
+    fn start() -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_aoc-server"))
+            .args(["--addr", "127.0.0.1:0", "--timeout", "5s"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().unwrap())
+            .read_line(&mut line)
+            .unwrap();
+        let addr = line
+            .trim()
+            .strip_prefix("listening on http://")
+            .unwrap()
+            .to_string();
+        Self { child, addr }
+    }
+
+    fn request(&self, method: &str, path: &str, body: &str) -> (u16, serde_json::Value) {
+        let mut stream = TcpStream::connect(&self.addr).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            self.addr,
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn test_solve_over_http() {
+    let server = Server::start();
+
+    let (status, json) = server.request(
+        "POST",
+        "/2023/1/1",
+        "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n",
+    );
+    assert_eq!(status, 200);
+    assert_eq!(json["answer"], "142");
+    assert!(json["seconds"].as_f64().is_some());
+
+    let (status, json) = server.request("POST", "/2023/11/2", "#.\n.#\n");
+    assert_eq!(status, 501);
+    assert_eq!(json["error"]["kind"], "unimplemented");
+
+    let (status, json) = server.request("POST", "/2019/1/1", "");
+    assert_eq!(status, 404);
+    assert!(json["error"].is_string());
+}
@@ -0,0 +1,38 @@
+//! Parts run in worker processes: these need the `aoc` binary itself.
+
+use std::process::Command;
+
+fn aoc(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .env_remove("AOC_INPUT")
+        .output()
+        .unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_runaway_part_times_out() {
+    // naively steps every ghost at once, which takes ages on the real input
+    let stdout = aoc(&["run", "2023", "8", "2", "--timeout", "500ms"]);
+    assert_eq!(stdout, "2023 day 08 part 2: timeout after 500ms\n");
+}
+
+#[test]
+fn test_panic_is_reported() {
+    let stdout = aoc(&["run", "2023", "11", "2", "--timeout", "10s"]);
+    assert!(
+        stdout.contains("panicked: not yet implemented"),
+        "unexpected output: {stdout}"
+    );
+}
+
+#[test]
+fn test_worker_answers() {
+    let stdout = aoc(&["run", "2023", "1", "--timeout", "10s"]);
+    let answers = stdout
+        .lines()
+        .map(|line| line.split_whitespace().nth(5).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(answers, ["55712", "55413"]);
+}
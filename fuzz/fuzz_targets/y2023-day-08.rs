@@ -0,0 +1,8 @@
+#![no_main]
+
+// both parts are unbounded, the parser they share is fuzzed instead
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = y2023_day_08::part1::parse_input(input);
+    }
+});
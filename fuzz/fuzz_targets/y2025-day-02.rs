@@ -0,0 +1,10 @@
+#![no_main]
+
+// both parts are unbounded, the ranges they share are parsed instead
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        for range in input.trim().split(',') {
+            let _ = range.parse::<y2025_day_02::part1::Range>();
+        }
+    }
+});
@@ -0,0 +1,61 @@
+//! Arbitrary input for every registered day, one cargo-fuzz target per day,
+//! e.g. `just run y2025-day-10`.
+//!
+//! The parsers are written against well-formed puzzle input, so a part
+//! returning an error is fine but a panic, an overflow included, is a
+//! failure, and so is a part running past the `-timeout` of `just run`. Each
+//! target starts from the `examples/` fixtures of its day and grows its
+//! corpus in the ignored `corpus/<target>`.
+
+use aoc::{
+    registry::{self, Part, Preset, Solver},
+    status,
+};
+use std::sync::OnceLock;
+
+/// Parts whose running time grows with the numbers in their input rather
+/// than its length, or that walk forever when the input has no way out, so
+/// any input the fuzzer tries may time out. Their days are fuzzed through
+/// the other part, or through their parser in the target.
+const UNBOUNDED: &[(u16, u8, Part)] = &[
+    // every seed of the ranges, one by one
+    (2023, 5, Part::Two),
+    // every way to hold the button of one long race
+    (2023, 6, Part::Two),
+    // following the map until `ZZZ`, or every ghost until they line up
+    (2023, 8, Part::One),
+    (2023, 8, Part::Two),
+    // every arrangement of the unfolded springs
+    (2023, 12, Part::Two),
+    // every id of the ranges
+    (2025, 2, Part::One),
+    (2025, 2, Part::Two),
+];
+
+/// Runs every part of `day` of `year` on `data`, when it is text.
+///
+/// Parts still calling `todo!()` are left out, they panic on any input, and
+/// so are the [`UNBOUNDED`] ones. Parameters take their example values,
+/// which fit the small inputs fuzzing produces.
+///
+/// # Panics
+///
+/// When the day isn't registered, or a part panics on `data`.
+pub fn solve(year: u16, day: u8, data: &[u8]) {
+    static SOLVERS: OnceLock<Vec<Solver>> = OnceLock::new();
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let solvers = SOLVERS.get_or_init(|| {
+        let day = registry::find(year, day).expect("fuzz targets are named after registered days");
+        Part::ALL
+            .into_iter()
+            .filter(|&part| !status::stubbed(day, part))
+            .filter(|&part| !UNBOUNDED.contains(&(year, day.day, part)))
+            .filter_map(|part| day.solver(part))
+            .collect()
+    });
+    for solver in solvers {
+        let _ = solver(input, Preset::Example);
+    }
+}